@@ -0,0 +1,242 @@
+use crate::{
+    analysis::find_first,
+    interface::{WithSeats, WithVotes},
+    SimpleElection,
+};
+
+/// Where the votes of a transfer come from.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TransferSource {
+    /// The candidacy with the given index loses the votes.
+    Candidacy(usize),
+    /// The votes come from abstention, increasing the total votes.
+    Abstention,
+}
+
+/// The smallest transfer of votes changing the seats of an election.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatFlip {
+    /// The number of votes transferred.
+    pub votes: u32,
+    /// The seats of every candidacy after the transfer.
+    pub seats: Vec<u16>,
+    /// The candidacies winning seats with the transfer.
+    pub gained: Vec<usize>,
+    /// The candidacies losing seats with the transfer.
+    pub lost: Vec<usize>,
+}
+
+fn transfer(
+    election: &mut SimpleElection,
+    base: &[u32],
+    from: TransferSource,
+    to: usize,
+    votes: u32,
+) -> Result<Vec<u16>, &'static str> {
+    election
+        .results
+        .iter_mut()
+        .zip(base)
+        .for_each(|(c, &v)| c.set_votes(v));
+
+    if let TransferSource::Candidacy(idx) = from {
+        election.results[idx].set_votes(base[idx] - votes);
+    }
+    election.results[to].set_votes(base[to] + votes);
+
+    election.compute()?;
+    Ok(election.results.iter().map(|c| c.get_seats()).collect())
+}
+
+/// Finds the smallest number of votes moved to a candidacy that changes the seats of the election.
+///
+/// The transfer is found with a binary search, recomputing the election with its method and threshold. The seats are
+/// considered changed as soon as any candidacy wins or loses a seat, which may be a third candidacy when the quota or
+/// the threshold depend on the total votes. The search assumes the seats do not return to the original ones as the
+/// transfer grows.
+///
+/// # Arguments
+///
+/// * `election` - The election, computed or not.
+/// * `from` - Where the votes come from.
+/// * `to` - The index of the candidacy receiving the votes.
+///
+/// # Returns
+///
+/// The smallest transfer and the resulting seats, or `None` if no transfer changes the seats.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::{minimal_flip, TransferSource};
+/// use electosim::*;
+///
+/// let election = election!(vec![candidacy!(1000), candidacy!(700), candidacy!(300)], 5, Method::DHONDT);
+///
+/// // Seats: 3, 2, 0. Moving 25 votes, 975 / 3 = 325 ties with 325 and the tie goes to the last candidacy.
+/// let flip = minimal_flip(&election, TransferSource::Candidacy(0), 2).unwrap().unwrap();
+/// assert_eq!(flip.votes, 25);
+/// assert_eq!(flip.seats, vec![2, 2, 1]);
+/// assert_eq!(flip.gained, vec![2]);
+/// assert_eq!(flip.lost, vec![0]);
+/// ```
+pub fn minimal_flip(
+    election: &SimpleElection,
+    from: TransferSource,
+    to: usize,
+) -> Result<Option<SeatFlip>, &'static str> {
+    let candidacies = election.results.len();
+    if to >= candidacies
+        || matches!(from, TransferSource::Candidacy(idx) if idx >= candidacies || idx == to)
+    {
+        return Err("INVALID_CANDIDACY");
+    }
+
+    let mut copy = election.clone();
+    let base = copy
+        .results
+        .iter()
+        .map(|c| c.get_votes())
+        .collect::<Vec<_>>();
+    let seats = transfer(&mut copy, &base, from, to, 0)?;
+
+    let hi = match from {
+        TransferSource::Candidacy(idx) => base[idx],
+        TransferSource::Abstention => {
+            // Sums of votes are u32, so the transfer is bounded by the headroom left in the total.
+            let total = copy.total_votes();
+            let others = (total - base[to]) as u64;
+            (others.max(1) * (election.seats as u64 + 1)).min((u32::MAX - total) as u64) as u32
+        }
+    };
+
+    if hi == 0 {
+        return Ok(None);
+    }
+
+    let votes = find_first(1, hi, |votes| {
+        Ok(transfer(&mut copy, &base, from, to, votes)? != seats)
+    })?;
+
+    match votes {
+        None => Ok(None),
+        Some(votes) => {
+            let flipped = transfer(&mut copy, &base, from, to, votes)?;
+            let gained = (0..candidacies)
+                .filter(|&i| flipped[i] > seats[i])
+                .collect();
+            let lost = (0..candidacies)
+                .filter(|&i| flipped[i] < seats[i])
+                .collect();
+
+            Ok(Some(SeatFlip {
+                votes,
+                seats: flipped,
+                gained,
+                lost,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::Candidacy, Method};
+
+    fn election() -> SimpleElection {
+        SimpleElection::new(
+            vec![
+                Candidacy::new(5000, 0),
+                Candidacy::new(3000, 0),
+                Candidacy::new(2000, 0),
+            ],
+            10,
+            Method::HARE,
+        )
+    }
+
+    #[test]
+    fn test_flip_between_candidacies() {
+        // Quotas: 5, 3, 2 exactly.
+        let flip = minimal_flip(&election(), TransferSource::Candidacy(0), 1)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(flip.votes, 500);
+        assert_eq!(flip.seats, vec![4, 4, 2]);
+        assert_eq!(flip.gained, vec![1]);
+        assert_eq!(flip.lost, vec![0]);
+    }
+
+    #[test]
+    fn test_flip_from_abstention() {
+        let flip = minimal_flip(&election(), TransferSource::Abstention, 2)
+            .unwrap()
+            .unwrap();
+
+        // Quotas with 834 more votes: 4.615, 2.769, 2.616.
+        assert_eq!(flip.votes, 834);
+        assert_eq!(flip.seats, vec![4, 3, 3]);
+        assert_eq!(flip.gained, vec![2]);
+        assert_eq!(flip.lost, vec![0]);
+    }
+
+    #[test]
+    fn test_flip_between_third_candidacies() {
+        let election = SimpleElection::new(
+            vec![
+                Candidacy::new(1000, 0),
+                Candidacy::new(3500, 0),
+                Candidacy::new(200, 0),
+            ],
+            10,
+            Method::HARE,
+        );
+
+        // Quotas: 2.128, 7.447, 0.426. With 15 more votes: 2.153, 7.423, 0.424, so the last seat moves from the
+        // second candidacy to the third one before the first one wins any.
+        let flip = minimal_flip(&election, TransferSource::Abstention, 0)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(flip.votes, 15);
+        assert_eq!(flip.seats, vec![2, 7, 1]);
+        assert_eq!(flip.gained, vec![2]);
+        assert_eq!(flip.lost, vec![1]);
+    }
+
+    #[test]
+    fn test_flip_from_abstention_national_scale() {
+        let election = SimpleElection::new(
+            vec![Candidacy::new(10_000_000, 0), Candidacy::new(20_000_000, 0)],
+            350,
+            Method::DHONDT,
+        );
+
+        // The last seat is a tie, 10M / 117 = 20M / 234, won by the last candidacy.
+        let flip = minimal_flip(&election, TransferSource::Abstention, 0)
+            .unwrap()
+            .unwrap();
+        assert_eq!(flip.votes, 1);
+        assert_eq!(flip.seats, vec![117, 233]);
+    }
+
+    #[test]
+    fn test_no_flip() {
+        let election = SimpleElection::new(
+            vec![Candidacy::new(100, 0), Candidacy::new(1, 0)],
+            1,
+            Method::DHONDT,
+        );
+
+        assert_eq!(
+            minimal_flip(&election, TransferSource::Candidacy(1), 0),
+            Ok(None)
+        );
+        assert_eq!(
+            minimal_flip(&election, TransferSource::Candidacy(0), 0),
+            Err("INVALID_CANDIDACY")
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    analysis::{find_first, seats_with_votes},
+    elections::{MultiDistrictElection, SwingModel},
+    interface::{WithSeats, WithVotes},
+    utils::distribute_votes,
+    SimpleElection,
+};
+
+/// Resolution of the shares searched by [national_share_for_seats], in parts per million.
+const SHARE_RESOLUTION: u32 = 1_000_000;
+
+/// Finds the minimum votes of a candidacy to win `target` seats, with the votes of the other candidacies fixed.
+///
+/// # Arguments
+///
+/// * `election` - The election, computed or not.
+/// * `idx` - The index of the candidacy.
+/// * `target` - The number of seats to reach.
+///
+/// # Returns
+///
+/// The minimum votes, or `None` if the target exceeds the seats of the election. `Err("OUT_OF_RANGE")` if the target
+/// would need more votes than the total votes can hold.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::votes_for_seats;
+/// use electosim::*;
+///
+/// let election = election!(vec![candidacy!(0), candidacy!(400), candidacy!(300)], 10, Method::DHONDT);
+///
+/// // An absolute majority needs a sixth quotient above 400 / 3 = 133.3: 801 / 6 = 133.5.
+/// assert_eq!(votes_for_seats(&election, 0, 6).unwrap(), Some(801));
+/// ```
+pub fn votes_for_seats(
+    election: &SimpleElection,
+    idx: usize,
+    target: u16,
+) -> Result<Option<u32>, &'static str> {
+    if idx >= election.results.len() {
+        return Err("INVALID_CANDIDACY");
+    }
+
+    if target > election.seats {
+        return Ok(None);
+    }
+
+    let mut copy = election.clone();
+    let others = copy.total_votes() - copy.results[idx].get_votes();
+
+    // The limit is doubled until the target is reached. Sums of votes are u32, so it is bounded by the headroom left
+    // by the other candidacies.
+    let headroom = u32::MAX - others;
+    let (mut lo, mut hi) = (0, others.max(1).min(headroom));
+    while seats_with_votes(&mut copy, idx, hi)? < target {
+        if hi == headroom {
+            return Err("OUT_OF_RANGE");
+        }
+        lo = hi + 1;
+        hi = hi.saturating_mul(2).min(headroom);
+    }
+
+    find_first(lo, hi, |votes| {
+        Ok(seats_with_votes(&mut copy, idx, votes)? >= target)
+    })
+}
+
+/// Finds the minimum share of a candidacy to win `target` seats, keeping the total votes and the relative strength of
+/// the other candidacies.
+///
+/// The rest of the votes is distributed among the other candidacies with [distribute_votes], so every election tested
+/// keeps the total votes exactly. The share is found with a binary search, which assumes the seats of the candidacy
+/// do not decrease when its share grows, as in [seat_margin][crate::analysis::seat_margin].
+///
+/// # Arguments
+///
+/// * `election` - The election, computed or not.
+/// * `idx` - The index of the candidacy.
+/// * `target` - The number of seats to reach.
+///
+/// # Returns
+///
+/// The minimum share of the total votes, or `None` if the target cannot be reached.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::share_for_seats;
+/// use electosim::*;
+///
+/// let election = election!(vec![candidacy!(4000), candidacy!(3000), candidacy!(3000)], 11, Method::DHONDT);
+///
+/// let share = share_for_seats(&election, 0, 6).unwrap().unwrap();
+/// assert!(share > 0.5 && share < 0.55);
+/// ```
+pub fn share_for_seats(
+    election: &SimpleElection,
+    idx: usize,
+    target: u16,
+) -> Result<Option<f32>, &'static str> {
+    if idx >= election.results.len() {
+        return Err("INVALID_CANDIDACY");
+    }
+
+    if target > election.seats {
+        return Ok(None);
+    }
+
+    let mut copy = election.clone();
+    let total = copy.total_votes();
+    let others = election
+        .results
+        .iter()
+        .enumerate()
+        .map(|(i, c)| match i == idx {
+            true => 0.0,
+            false => c.get_votes() as f64,
+        })
+        .collect::<Vec<_>>();
+
+    let votes = find_first(0, total, |votes| {
+        for (c, v) in copy
+            .results
+            .iter_mut()
+            .zip(distribute_votes(&others, total - votes))
+        {
+            c.set_votes(v);
+        }
+        Ok(seats_with_votes(&mut copy, idx, votes)? >= target)
+    })?;
+
+    Ok(votes.map(|v| v as f32 / total as f32))
+}
+
+/// Finds the minimum national share of a party to win `target` seats in a multi-district election.
+///
+/// The votes of every district are projected to each national share with `model`, as in
+/// [MultiDistrictElection::swing], and the seats of the party are added up. The other parties share the rest of the
+/// votes keeping their relative national strength.
+///
+/// # Arguments
+///
+/// * `election` - The baseline election.
+/// * `id` - The id of the party.
+/// * `target` - The number of seats to reach.
+/// * `model` - The swing model used to project the national share into the districts.
+///
+/// # Returns
+///
+/// The minimum national share, with a resolution of one millionth, or `None` if the target cannot be reached.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::national_share_for_seats;
+/// use electosim::elections::{MultiDistrictElection, SwingModel};
+/// use electosim::*;
+///
+/// let election = MultiDistrictElection::new()
+///     .with_district("North", election!(vec![candidacy!(450).with_id("A"), candidacy!(550).with_id("B")], 3, Method::DHONDT))
+///     .with_district("South", election!(vec![candidacy!(350).with_id("A"), candidacy!(650).with_id("B")], 3, Method::DHONDT));
+///
+/// // A majority of 4 seats needs 2 seats in both districts.
+/// let share = national_share_for_seats(&election, "A", 4, SwingModel::Uniform).unwrap().unwrap();
+/// assert!(share > 0.5 && share < 0.75);
+/// ```
+pub fn national_share_for_seats(
+    election: &MultiDistrictElection,
+    id: &str,
+    target: u16,
+    model: SwingModel,
+) -> Result<Option<f32>, &'static str> {
+    if target > election.seats() {
+        return Ok(None);
+    }
+
+    let totals = election.totals()?;
+    let national_votes = totals.iter().map(|t| t.votes as f64).sum::<f64>();
+    let others_votes = totals
+        .iter()
+        .filter(|t| t.id != id)
+        .map(|t| t.votes as f64)
+        .sum::<f64>();
+
+    let seats_with_share = |share: u32| -> Result<u16, &'static str> {
+        let share = share as f64 / SHARE_RESOLUTION as f64;
+
+        // The other parties keep their relative strength in the rest of the votes.
+        let shares = totals
+            .iter()
+            .filter(|_| national_votes > 0.0)
+            .map(|t| {
+                let target = match t.id == id {
+                    true => share,
+                    false if others_votes > 0.0 => (1.0 - share) * t.votes as f64 / others_votes,
+                    false => 0.0,
+                };
+                (t.id.clone(), target as f32)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut copy = election.clone();
+        copy.swing(model, &shares)?;
+
+        Ok(copy
+            .districts
+            .iter()
+            .flat_map(|d| d.election.results.iter())
+            .filter(|c| c.id() == Some(id))
+            .map(|c| c.get_seats())
+            .sum())
+    };
+
+    let share = find_first(0, SHARE_RESOLUTION, |share| {
+        Ok(seats_with_share(share)? >= target)
+    })?;
+
+    Ok(share.map(|s| s as f32 / SHARE_RESOLUTION as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::Candidacy, Method};
+
+    #[test]
+    fn test_votes_for_seats() {
+        let election = SimpleElection {
+            cutoff: 0.05,
+            ..SimpleElection::new(
+                vec![
+                    Candidacy::new(10, 0),
+                    Candidacy::new(600, 0),
+                    Candidacy::new(390, 0),
+                ],
+                10,
+                Method::SAINTELAGUE,
+            )
+        };
+
+        // 53 votes exceed 5% of the 1043 votes, but the last quotient is 390 / 7 = 55.7 and then 600 / 11 = 54.5.
+        assert_eq!(votes_for_seats(&election, 0, 1).unwrap(), Some(55));
+        assert_eq!(votes_for_seats(&election, 0, 0).unwrap(), Some(0));
+        assert_eq!(votes_for_seats(&election, 0, 11).unwrap(), None);
+    }
+
+    #[test]
+    fn test_votes_for_all_seats() {
+        let election = SimpleElection::new(
+            vec![
+                Candidacy::new(0, 0),
+                Candidacy::new(600, 0),
+                Candidacy::new(390, 0),
+            ],
+            10,
+            Method::SAINTELAGUE,
+        );
+
+        // The tenth quotient must exceed 600: 19 * 600 = 11400.
+        assert_eq!(votes_for_seats(&election, 0, 10).unwrap(), Some(11401));
+
+        let election = SimpleElection::new(
+            vec![Candidacy::new(0, 0), Candidacy::new(100, 0)],
+            2,
+            Method::HARE,
+        );
+
+        // The remainder seat is a tie at 300 votes: 1.5 and 0.5 quotas.
+        assert_eq!(votes_for_seats(&election, 0, 2).unwrap(), Some(301));
+    }
+
+    #[test]
+    fn test_votes_for_seats_national_scale() {
+        let election = SimpleElection::new(
+            vec![Candidacy::new(10_000_000, 0), Candidacy::new(20_000_000, 0)],
+            350,
+            Method::DHONDT,
+        );
+
+        // The 175th quotient must exceed 20M / 176: 175 * 20M / 176 = 19,886,363.6, up to the f32 precision.
+        let votes = votes_for_seats(&election, 0, 175).unwrap().unwrap();
+        assert!(votes.abs_diff(19_886_364) <= 4);
+
+        // Every seat needs a 350th quotient above 20M, beyond the votes the total can hold.
+        assert_eq!(votes_for_seats(&election, 0, 350), Err("OUT_OF_RANGE"));
+    }
+
+    #[test]
+    fn test_share_for_seats() {
+        let election = SimpleElection::new(
+            vec![
+                Candidacy::new(500, 0),
+                Candidacy::new(250, 0),
+                Candidacy::new(250, 0),
+            ],
+            2,
+            Method::HARE,
+        );
+
+        // Two Hare seats: the other candidacies need less than half a quota each.
+        let share = share_for_seats(&election, 0, 2).unwrap().unwrap();
+        assert!((share - 0.667).abs() < 0.002);
+    }
+
+    #[test]
+    fn test_national_share_for_seats() {
+        let district = |a, b| {
+            SimpleElection::new(
+                vec![
+                    Candidacy::new(a, 0).with_id("A"),
+                    Candidacy::new(b, 0).with_id("B"),
+                ],
+                1,
+                Method::WINNERTAKESALL,
+            )
+        };
+        let election = MultiDistrictElection::new()
+            .with_district("1", district(450, 550))
+            .with_district("2", district(350, 650))
+            .with_district("3", district(700, 300));
+
+        // National share 50%. Winning the first district needs a uniform swing above 5 points.
+        let two = national_share_for_seats(&election, "A", 2, SwingModel::Uniform)
+            .unwrap()
+            .unwrap();
+        assert!((two - 0.55).abs() < 1e-3);
+
+        // Winning the second district too needs a uniform swing above 15 points.
+        let three = national_share_for_seats(&election, "A", 3, SwingModel::Uniform)
+            .unwrap()
+            .unwrap();
+        assert!((three - 0.65).abs() < 1e-3);
+        assert_eq!(
+            national_share_for_seats(&election, "A", 4, SwingModel::Uniform),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    methods::divisor::compute_divisor_method,
+};
+
+/// A seat of a divisor method and the quotient that won it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DivisorSeat {
+    /// The number of the seat, in the order the seats are awarded.
+    pub seat: u16,
+    /// The index of the candidacy winning the seat.
+    pub candidacy: usize,
+    /// The quotient of the candidacy for the seat.
+    pub quotient: f32,
+}
+
+/// The last seat awarded by a divisor method and the next one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastSeat {
+    /// The last seat awarded.
+    pub last: DivisorSeat,
+    /// The first seat not awarded, which would be won with one more seat.
+    pub next: DivisorSeat,
+    /// The additional votes the claimant of the next seat needs to exceed the quotient of the last seat, or `None` if
+    /// the same candidacy wins both seats.
+    pub votes_gap: Option<u32>,
+}
+
+/// Computes a divisor method and finds the last seat awarded ("último escaño") and the next one.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidacies.
+/// * `seats` - The number of seats available.
+/// * `divisor` - A function that takes the number of seats won by the candidate and returns a float number.
+///
+/// # Returns
+///
+/// The last and next seats, with the seats of the candidacies set on `results`.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::compute_last_seat;
+/// use electosim::*;
+///
+/// let mut results = vec![candidacy!(1000), candidacy!(700), candidacy!(300)];
+///
+/// // Quotients: 1000, 700, 500, 350, 333.3 | 300, 250.
+/// let last_seat = compute_last_seat(&mut results, 5, |s| (s + 1) as f32).unwrap();
+///
+/// assert_eq!(last_seat.last.candidacy, 0);
+/// assert_eq!(last_seat.next.seat, 6);
+/// assert_eq!(last_seat.next.candidacy, 2);
+/// assert_eq!(last_seat.votes_gap, Some(34));
+/// ```
+pub fn compute_last_seat<T>(
+    results: &mut [T],
+    seats: u16,
+    divisor: impl Fn(u16) -> f32,
+) -> Result<LastSeat, &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    if seats == 0 {
+        return Err("NOT_ENOUGH_SEATS");
+    }
+
+    compute_divisor_method(results, seats, &divisor)?;
+
+    // Ties are awarded to the last candidacy, so the last seat goes to the first one among the lowest quotients.
+    let (last_idx, last_quotient) = results
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.get_seats() > 0)
+        .map(|(idx, c)| (idx, c.get_votes() as f32 / divisor(c.get_seats() - 1)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .ok_or("EMPTY_RESULTS")?;
+
+    let (next_idx, next_quotient) = results
+        .iter()
+        .map(|c| c.get_votes() as f32 / divisor(c.get_seats()))
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .ok_or("EMPTY_RESULTS")?;
+
+    // (votes + gap) / divisor(seats) > votes_last / divisor(seats_last - 1).
+    let last = &results[last_idx];
+    let next = &results[next_idx];
+    let votes_gap = match last_idx == next_idx {
+        true => None,
+        false => {
+            let needed = last.get_votes() as f64 * divisor(next.get_seats()) as f64
+                / divisor(last.get_seats() - 1) as f64
+                - next.get_votes() as f64;
+            Some((needed.floor() + 1.0).max(0.0) as u32)
+        }
+    };
+
+    Ok(LastSeat {
+        last: DivisorSeat {
+            seat: seats,
+            candidacy: last_idx,
+            quotient: last_quotient,
+        },
+        next: DivisorSeat {
+            seat: seats + 1,
+            candidacy: next_idx,
+            quotient: next_quotient,
+        },
+        votes_gap,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    #[test]
+    fn test_sainte_lague_last_seat() {
+        let mut results = vec![
+            Candidacy::new(5000, 0),
+            Candidacy::new(2600, 0),
+            Candidacy::new(1400, 0),
+        ];
+
+        // Quotients: 5000, 2600, 1666.7, 1400, 1000, 866.7, 714.3 | 555.6, 520, 466.7.
+        let last_seat = compute_last_seat(&mut results, 7, |s| (2 * s + 1) as f32).unwrap();
+
+        assert_eq!(last_seat.last.seat, 7);
+        assert_eq!(last_seat.last.candidacy, 0);
+        assert!((last_seat.last.quotient - 714.2857).abs() < 1e-3);
+        assert_eq!(last_seat.next.candidacy, 0);
+        assert!((last_seat.next.quotient - 555.5556).abs() < 1e-3);
+        // The first candidacy wins both seats, so there is no gap to close.
+        assert_eq!(last_seat.votes_gap, None);
+        assert_eq!(
+            results.iter().map(|c| c.get_seats()).collect::<Vec<_>>(),
+            vec![4, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_votes_gap() {
+        let mut results = vec![Candidacy::new(900, 0), Candidacy::new(500, 0)];
+
+        // Quotients: 900, 500, 450, 300 | 250, 225.
+        let last_seat = compute_last_seat(&mut results, 4, |s| (s + 1) as f32).unwrap();
+
+        assert_eq!(last_seat.last.candidacy, 0);
+        assert_eq!(last_seat.next.candidacy, 1);
+        // 500 + 101 = 601, 601 / 2 > 300.
+        assert_eq!(last_seat.votes_gap, Some(101));
+
+        assert_eq!(
+            compute_last_seat(&mut results, 0, |s| (s + 1) as f32),
+            Err("NOT_ENOUGH_SEATS")
+        );
+    }
+}
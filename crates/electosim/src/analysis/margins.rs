@@ -0,0 +1,193 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    SimpleElection,
+};
+
+/// How many votes a candidacy is from winning or losing a seat, with the other candidacies fixed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatMargin {
+    /// The seats won by the candidacy.
+    pub seats: u16,
+    /// The minimum number of additional votes to win one more seat, if possible.
+    pub votes_to_gain: Option<u32>,
+    /// The minimum number of lost votes to lose a seat, if it has any.
+    /// The candidacy can lose one vote less and keep its seats.
+    pub votes_to_lose: Option<u32>,
+}
+
+/// Computes the seats of a candidacy of a computed copy of the election with `votes` votes.
+pub(crate) fn seats_with_votes(
+    election: &mut SimpleElection,
+    idx: usize,
+    votes: u32,
+) -> Result<u16, &'static str> {
+    election.results[idx].set_votes(votes);
+    election.compute()?;
+    Ok(election.results[idx].get_seats())
+}
+
+/// Finds the smallest value in `lo..=hi` satisfying `predicate`, assuming it holds for every value after the first one.
+pub(crate) fn find_first(
+    mut lo: u32,
+    mut hi: u32,
+    mut predicate: impl FnMut(u32) -> Result<bool, &'static str>,
+) -> Result<Option<u32>, &'static str> {
+    if !predicate(hi)? {
+        return Ok(None);
+    }
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match predicate(mid)? {
+            true => hi = mid,
+            false => lo = mid + 1,
+        }
+    }
+
+    Ok(Some(lo))
+}
+
+/// Computes the margin of a candidacy of the election.
+///
+/// The margins are found with a binary search, recomputing the election with the method and threshold of the election.
+/// They are exact as long as the seats of a candidacy do not decrease when it wins votes, which holds for the divisor
+/// and largest remainder methods.
+///
+/// # Arguments
+///
+/// * `election` - The election, computed or not.
+/// * `idx` - The index of the candidacy.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::analysis::seat_margin;
+/// use electosim::*;
+///
+/// let election = election!(vec![candidacy!(600), candidacy!(400)], 4, Method::DHONDT);
+///
+/// // The last seat is a tie, 600 / 3 = 400 / 2, won by the last candidacy.
+/// let margin = seat_margin(&election, 0).unwrap();
+/// assert_eq!(margin.seats, 2);
+/// assert_eq!(margin.votes_to_gain, Some(1));
+/// // 266 / 2 = 133 < 400 / 3 = 133.3.
+/// assert_eq!(margin.votes_to_lose, Some(334));
+/// ```
+pub fn seat_margin(election: &SimpleElection, idx: usize) -> Result<SeatMargin, &'static str> {
+    if idx >= election.results.len() {
+        return Err("INVALID_CANDIDACY");
+    }
+
+    let mut copy = election.clone();
+    let votes = copy.results[idx].get_votes();
+    let seats = seats_with_votes(&mut copy, idx, votes)?;
+
+    // Sums of votes are u32, so the gain is bounded by the headroom left in the total.
+    let total = copy.total_votes();
+    let others = (total - votes) as u64;
+    let headroom = (u32::MAX - total) as u64;
+    let hi = (others.max(1) * (election.seats as u64 + 1)).min(headroom) as u32;
+
+    let votes_to_gain = match seats < election.seats && hi > 0 {
+        true => find_first(1, hi, |gain| {
+            Ok(seats_with_votes(&mut copy, idx, votes + gain)? > seats)
+        })?,
+        false => None,
+    };
+
+    let votes_to_lose = match seats > 0 {
+        true => find_first(1, votes, |loss| {
+            Ok(seats_with_votes(&mut copy, idx, votes - loss)? < seats)
+        })?,
+        false => None,
+    };
+
+    Ok(SeatMargin {
+        seats,
+        votes_to_gain,
+        votes_to_lose,
+    })
+}
+
+/// Computes the margin of every candidacy of the election.
+///
+/// See [seat_margin].
+pub fn seat_margins(election: &SimpleElection) -> Result<Vec<SeatMargin>, &'static str> {
+    (0..election.results.len())
+        .map(|idx| seat_margin(election, idx))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::Candidacy, Method};
+
+    #[test]
+    fn test_dhondt_margins() {
+        let election = SimpleElection::new(
+            vec![
+                Candidacy::new(1000, 0),
+                Candidacy::new(700, 0),
+                Candidacy::new(300, 0),
+            ],
+            5,
+            Method::DHONDT,
+        );
+
+        // Quotients: 1000, 700, 500, 350, 333 | 300, 250, 233.
+        let margins = seat_margins(&election).unwrap();
+
+        assert_eq!(margins[0].seats, 3);
+        assert_eq!(margins[1].seats, 2);
+        assert_eq!(margins[2].seats, 0);
+
+        // 300 + 34 = 334 > 333.3.
+        assert_eq!(margins[2].votes_to_gain, Some(34));
+        assert_eq!(margins[2].votes_to_lose, None);
+        // 900 / 3 = 300 ties with the third candidacy, which wins the tie.
+        assert_eq!(margins[0].votes_to_lose, Some(100));
+    }
+
+    #[test]
+    fn test_cutoff_margins() {
+        let election = SimpleElection {
+            cutoff: 0.1,
+            ..SimpleElection::new(
+                vec![Candidacy::new(950, 0), Candidacy::new(100, 0)],
+                10,
+                Method::HARE,
+            )
+        };
+
+        let margins = seat_margins(&election).unwrap();
+
+        // 100 of 1050 votes is below 10%, 106 of 1056 exceeds it.
+        assert_eq!(margins[1].seats, 0);
+        assert_eq!(margins[1].votes_to_gain, Some(6));
+        assert_eq!(margins[0].votes_to_gain, None);
+    }
+
+    #[test]
+    fn test_national_scale_margins() {
+        let election = SimpleElection::new(
+            vec![Candidacy::new(10_000_000, 0), Candidacy::new(20_000_000, 0)],
+            350,
+            Method::DHONDT,
+        );
+
+        // The last seat is a tie, 10M / 117 = 20M / 234, won by the last candidacy.
+        let margin = seat_margin(&election, 0).unwrap();
+        assert_eq!(margin.seats, 116);
+        assert_eq!(margin.votes_to_gain, Some(1));
+        // 9,872,339 / 116 < 20M / 235.
+        assert_eq!(margin.votes_to_lose, Some(127_660));
+    }
+
+    #[test]
+    fn test_invalid_candidacy() {
+        let election = SimpleElection::new(vec![Candidacy::new(1, 0)], 1, Method::DHONDT);
+
+        assert_eq!(seat_margin(&election, 1), Err("INVALID_CANDIDACY"));
+    }
+}
@@ -0,0 +1,17 @@
+//! # Analysis
+//! Analysis of the results of an election: how many votes each candidacy is from winning or losing a seat,
+//! which candidacies won the last seat and would win the next one, the smallest transfer of votes changing the seats,
+//! and the votes or share needed to reach a number of seats.
+//!
+//! Most functions of this module recompute the election with modified votes, so they work with any [Method][crate::Method]
+//! and take the threshold of the election into account.
+
+mod flip;
+mod inverse;
+mod last_seat;
+mod margins;
+
+pub use flip::*;
+pub use inverse::*;
+pub use last_seat::*;
+pub use margins::*;
@@ -0,0 +1,159 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    utils::clear_results,
+};
+
+/// A divisor method with a fixed base of seats per state and a cap.
+///
+/// Every state receives `base` seats. The remaining seats are assigned one by one to the state with the highest result of
+/// the division of its population by the divisor, which takes the number of seats won beyond the base.
+/// States with `max` seats receive no more seats.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of states.
+/// * `seats` - The number of seats of the chamber.
+/// * `divisor` - A function that takes the number of seats won beyond the base and returns a float number.
+/// * `base` - The seats given to every state.
+/// * `max` - The maximum number of seats of a state.
+///
+/// # Returns
+///
+/// An error if the bounds are inconsistent (`INVALID_BOUNDS`) or cannot be fulfilled with the available seats
+/// (`NOT_ENOUGH_SEATS`, `TOO_MANY_SEATS`).
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::apportionment::compute_bounded_divisor_method;
+/// use electosim::interface::WithSeats;
+/// use electosim::models::Candidacy;
+///
+/// let mut states = vec![
+///     Candidacy::new(80000, 0),
+///     Candidacy::new(10000, 0),
+///     Candidacy::new(1000, 0),
+/// ];
+///
+/// compute_bounded_divisor_method(&mut states, 20, |s| (s + 1) as f32, 2, 14).unwrap();
+/// assert_eq!(states.iter().map(|s| s.get_seats()).collect::<Vec<_>>(), vec![14, 4, 2]);
+/// ```
+pub fn compute_bounded_divisor_method<T>(
+    results: &mut [T],
+    seats: u16,
+    divisor: impl Fn(u16) -> f32,
+    base: u16,
+    max: u16,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+    let states = results.len() as u32;
+
+    if results.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if base > max {
+        return Err("INVALID_BOUNDS");
+    }
+
+    if base as u32 * states > seats as u32 {
+        return Err("NOT_ENOUGH_SEATS");
+    }
+
+    if max as u32 * states < seats as u32 {
+        return Err("TOO_MANY_SEATS");
+    }
+
+    results.iter_mut().for_each(|r| r.set_seats(base));
+
+    for _ in 0..seats - base * states as u16 {
+        let better_idx = results
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.get_seats() < max)
+            .map(|(idx, c)| (idx, c.get_votes() as f32 / divisor(c.get_seats() - base)))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        match better_idx {
+            Some((idx, _)) => results[idx].increase_seats(1),
+            None => return Err("TOO_MANY_SEATS"),
+        }
+    }
+
+    Ok(())
+}
+
+/// The Cambridge Compromise for the European Parliament.
+///
+/// Every state receives a base of 5 seats and the rest are assigned by the Adams method (rounding up), with a cap of 96 seats per state.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of states.
+/// * `seats` - The number of seats of the chamber.
+///
+/// See more at [Cambridge Compromise](https://en.wikipedia.org/wiki/Cambridge_Compromise)
+pub fn compute_cambridge_compromise<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    compute_bounded_divisor_method(results, seats, |s| s as f32, 5, 96)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{apportionment::is_degressively_proportional, models::Candidacy};
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_cambridge_compromise() {
+        // Populations in thousands.
+        let mut states = vec![
+            Candidacy::new(83000, 0),
+            Candidacy::new(67000, 0),
+            Candidacy::new(47000, 0),
+            Candidacy::new(10000, 0),
+            Candidacy::new(2000, 0),
+            Candidacy::new(500, 0),
+        ];
+
+        compute_cambridge_compromise(&mut states, 150).unwrap();
+
+        assert_eq!(seats(&states).iter().sum::<u16>(), 150);
+        assert_eq!(seats(&states), vec![51, 43, 32, 11, 7, 6]);
+        assert!(is_degressively_proportional(&states));
+    }
+
+    #[test]
+    fn test_cap() {
+        let mut states = vec![Candidacy::new(1000, 0), Candidacy::new(10, 0)];
+
+        compute_bounded_divisor_method(&mut states, 150, |s| s as f32, 5, 96).unwrap();
+        assert_eq!(seats(&states), vec![96, 54]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut states = vec![Candidacy::new(1000, 0), Candidacy::new(10, 0)];
+
+        assert_eq!(
+            compute_bounded_divisor_method(&mut states, 9, |s| s as f32, 5, 96),
+            Err("NOT_ENOUGH_SEATS")
+        );
+        assert_eq!(
+            compute_bounded_divisor_method(&mut states, 200, |s| s as f32, 5, 96),
+            Err("TOO_MANY_SEATS")
+        );
+        assert_eq!(
+            compute_bounded_divisor_method(&mut states, 20, |s| s as f32, 6, 5),
+            Err("INVALID_BOUNDS")
+        );
+    }
+}
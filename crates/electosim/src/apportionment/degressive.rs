@@ -0,0 +1,60 @@
+use crate::interface::{WithSeats, WithVotes};
+
+/// Finds the pairs of states violating degressive proportionality.
+///
+/// An apportionment is degressively proportional when a more populous state never has fewer seats than a less populous one,
+/// and each of its seats never represents fewer people.
+///
+/// # Arguments
+///
+/// * `results` - A slice of states implementing the `WithSeats` and `WithVotes` traits, where votes are the population.
+///
+/// # Returns
+///
+/// The pairs `(smaller, larger)` of state indexes violating degressive proportionality.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::apportionment::degressive_proportionality_violations;
+/// use electosim::models::Candidacy;
+///
+/// let states = vec![
+///     Candidacy::new(1000, 10),
+///     Candidacy::new(400, 6),
+///     Candidacy::new(300, 2),
+/// ];
+///
+/// // State 2 has 150 people per seat, more than states 0 (100) and 1 (66).
+/// assert_eq!(degressive_proportionality_violations(&states), vec![(2, 0), (2, 1)]);
+/// ```
+pub fn degressive_proportionality_violations<C: WithSeats + WithVotes>(
+    results: &[C],
+) -> Vec<(usize, usize)> {
+    let mut violations = vec![];
+
+    for (i, small) in results.iter().enumerate() {
+        for (j, large) in results.iter().enumerate() {
+            if small.get_votes() >= large.get_votes() {
+                continue;
+            }
+
+            let (p_s, s_s) = (small.get_votes() as u64, small.get_seats() as u64);
+            let (p_l, s_l) = (large.get_votes() as u64, large.get_seats() as u64);
+
+            // p_s / s_s > p_l / s_l
+            if s_s > s_l || p_s * s_l > p_l * s_s {
+                violations.push((i, j));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Checks whether an apportionment is degressively proportional.
+///
+/// See [degressive_proportionality_violations] for the definition used.
+pub fn is_degressively_proportional<C: WithSeats + WithVotes>(results: &[C]) -> bool {
+    degressive_proportionality_violations(results).is_empty()
+}
@@ -0,0 +1,159 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    methods::{get_method_function, Method},
+    utils::clear_results,
+};
+
+/// How the magnitude of a district is decided.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DistrictRule {
+    /// A minimum number of seats, plus a share of the remaining seats by population.
+    Minimum(u16),
+    /// A fixed number of seats. The district takes no part in the distribution by population.
+    Fixed(u16),
+}
+
+/// Computes the magnitude of each district from its population.
+///
+/// Fixed districts receive their seats, the rest receive their minimum, and the remaining seats are distributed
+/// among the non-fixed districts with `method` over their populations.
+/// The magnitudes are set as the seats of `districts`, ready to build the [SimpleElection][crate::SimpleElection] of each district.
+///
+/// # Arguments
+///
+/// * `districts` - A mutable reference to a vector of districts, where votes are the population.
+/// * `seats` - The number of seats of the chamber.
+/// * `rules` - The rule of each district, in the same order as `districts`.
+/// * `method` - The method used to distribute the remaining seats.
+///
+/// # Returns
+///
+/// `Err("RULES_MISMATCH")` if there is not one rule per district, `Err("NOT_ENOUGH_SEATS")` if the fixed
+/// and minimum seats exceed `seats` and `Err("NO_VARIABLE_DISTRICTS")` if seats remain but every district is fixed.
+///
+/// # Example (Spanish Congress, LOREG art. 162)
+///
+/// ```rust
+/// use electosim::apportionment::{compute_district_magnitudes, DistrictRule};
+/// use electosim::interface::WithSeats;
+/// use electosim::*;
+///
+/// // 50 provinces with a minimum of 2 seats, Ceuta and Melilla with 1 seat each.
+/// let mut districts = (0..52).map(|i| candidacy!(100_000 + 10_000 * i)).collect::<Vec<_>>();
+/// let mut rules = vec![DistrictRule::Minimum(2); 50];
+/// rules.extend([DistrictRule::Fixed(1), DistrictRule::Fixed(1)]);
+///
+/// compute_district_magnitudes(&mut districts, 350, &rules, Method::HARE).unwrap();
+///
+/// let magnitudes = districts.iter().map(|d| d.get_seats()).collect::<Vec<_>>();
+/// assert_eq!(magnitudes.iter().sum::<u16>(), 350);
+/// assert_eq!(magnitudes[51], 1);
+/// ```
+pub fn compute_district_magnitudes<T>(
+    districts: &mut [T],
+    seats: u16,
+    rules: &[DistrictRule],
+    method: Method,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(districts);
+
+    if rules.len() != districts.len() {
+        return Err("RULES_MISMATCH");
+    }
+
+    let reserved: u32 = rules
+        .iter()
+        .map(|r| match *r {
+            DistrictRule::Minimum(s) | DistrictRule::Fixed(s) => s as u32,
+        })
+        .sum();
+
+    if reserved > seats as u32 {
+        return Err("NOT_ENOUGH_SEATS");
+    }
+
+    let remaining = seats - reserved as u16;
+    let mut variable = districts
+        .iter_mut()
+        .zip(rules)
+        .filter(|(_, r)| matches!(r, DistrictRule::Minimum(_)))
+        .map(|(d, _)| Box::new(d))
+        .collect::<Vec<_>>();
+
+    if remaining > 0 {
+        if variable.is_empty() {
+            return Err("NO_VARIABLE_DISTRICTS");
+        }
+        get_method_function(method)(&mut variable, remaining)?;
+    }
+
+    districts.iter_mut().zip(rules).for_each(|(d, r)| match *r {
+        DistrictRule::Minimum(s) => d.set_seats(d.get_seats() + s),
+        DistrictRule::Fixed(s) => d.set_seats(s),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_district_magnitudes() {
+        let mut districts = vec![
+            Candidacy::new(6_000_000, 0),
+            Candidacy::new(3_000_000, 0),
+            Candidacy::new(1_000_000, 0),
+            Candidacy::new(100_000, 0),
+            Candidacy::new(80_000, 0),
+        ];
+        let rules = [
+            DistrictRule::Minimum(2),
+            DistrictRule::Minimum(2),
+            DistrictRule::Minimum(2),
+            DistrictRule::Minimum(2),
+            DistrictRule::Fixed(1),
+        ];
+
+        // 21 seats: 8 minimum, 1 fixed and 12 by Hare over 10.1 million people.
+        compute_district_magnitudes(&mut districts, 21, &rules, Method::HARE).unwrap();
+        assert_eq!(seats(&districts), vec![9, 6, 3, 2, 1]);
+
+        // Without remaining seats every district keeps its minimum.
+        compute_district_magnitudes(&mut districts, 9, &rules, Method::HARE).unwrap();
+        assert_eq!(seats(&districts), vec![2, 2, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut districts = vec![Candidacy::new(1000, 0), Candidacy::new(500, 0)];
+        let rules = [DistrictRule::Minimum(2), DistrictRule::Fixed(1)];
+
+        assert_eq!(
+            compute_district_magnitudes(&mut districts, 2, &rules, Method::HARE),
+            Err("NOT_ENOUGH_SEATS")
+        );
+        assert_eq!(
+            compute_district_magnitudes(&mut districts, 10, &rules[..1], Method::HARE),
+            Err("RULES_MISMATCH")
+        );
+        assert_eq!(
+            compute_district_magnitudes(
+                &mut districts,
+                10,
+                &[DistrictRule::Fixed(1); 2],
+                Method::HARE
+            ),
+            Err("NO_VARIABLE_DISTRICTS")
+        );
+    }
+}
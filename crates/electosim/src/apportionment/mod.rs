@@ -0,0 +1,18 @@
+//! # Apportionment
+//! Apportionment distributes the seats of a chamber among states or districts according to their population,
+//! usually with additional constraints such as a minimum or maximum number of seats per state.
+//!
+//! Populations are given as the votes of a [`WithVotes`][crate::interface::WithVotes] item,
+//! so any type used in the [methods][crate::methods] can be apportioned.
+
+mod bounded;
+mod degressive;
+mod districts;
+mod power;
+mod priority;
+
+pub use bounded::*;
+pub use degressive::*;
+pub use districts::*;
+pub use power::*;
+pub use priority::*;
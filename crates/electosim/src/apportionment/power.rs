@@ -0,0 +1,148 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    methods::{get_method_function, Method},
+    models::Candidacy,
+    utils::clear_results,
+};
+
+/// The weight of a state and the seats it receives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedSeats {
+    /// The transformed population of the state.
+    pub weight: f32,
+    /// The seats (or votes in a weighted council) received by the state.
+    pub seats: u16,
+}
+
+/// Apportions seats proportionally to a power of the population.
+///
+/// The populations are transformed into weights `population ^ power` and the seats are computed with `method` over the weights.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of states, where votes are the population.
+/// * `seats` - The number of seats (or votes of the council) to distribute.
+/// * `method` - The method used to distribute the seats over the weights.
+/// * `power` - The exponent applied to the populations.
+///
+/// # Returns
+///
+/// The weight and seats of each state, also set on `results`.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::apportionment::compute_power_apportionment;
+/// use electosim::*;
+///
+/// let mut states = vec![candidacy!(90000), candidacy!(40000), candidacy!(10000)];
+///
+/// let weighted = compute_power_apportionment(&mut states, 12, Method::HARE, 0.5).unwrap();
+/// assert_eq!(weighted.iter().map(|w| w.weight).collect::<Vec<_>>(), vec![300.0, 200.0, 100.0]);
+/// assert_eq!(weighted.iter().map(|w| w.seats).collect::<Vec<_>>(), vec![6, 4, 2]);
+/// ```
+pub fn compute_power_apportionment<T>(
+    results: &mut [T],
+    seats: u16,
+    method: Method,
+    power: f32,
+) -> Result<Vec<WeightedSeats>, &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+
+    let weights = results
+        .iter()
+        .map(|c| (c.get_votes() as f64).powf(power as f64))
+        .collect::<Vec<_>>();
+
+    // Weights are scaled to keep the precision of the methods, which work with integer votes.
+    let total: f64 = weights.iter().sum();
+    let scale = match total > 0.0 {
+        true => 1e9 / total,
+        false => 0.0,
+    };
+
+    let mut scaled = weights
+        .iter()
+        .map(|w| Candidacy::new((w * scale).round() as u32, 0))
+        .collect::<Vec<_>>();
+
+    get_method_function(method)(&mut scaled, seats)?;
+
+    Ok(results
+        .iter_mut()
+        .zip(weights.iter().zip(&scaled))
+        .map(|(c, (&weight, s))| {
+            c.set_seats(s.get_seats());
+            WeightedSeats {
+                weight: weight as f32,
+                seats: s.get_seats(),
+            }
+        })
+        .collect())
+}
+
+/// Penrose square-root apportionment, for weighted voting bodies.
+///
+/// Equivalent to [compute_power_apportionment] with `power = 0.5`.
+///
+/// See more at [Penrose method](https://en.wikipedia.org/wiki/Penrose_method)
+pub fn compute_penrose<T>(
+    results: &mut [T],
+    seats: u16,
+    method: Method,
+) -> Result<Vec<WeightedSeats>, &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    compute_power_apportionment(results, seats, method, 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_penrose() {
+        // Populations in thousands.
+        let mut states = vec![
+            Candidacy::new(83000, 0),
+            Candidacy::new(48000, 0),
+            Candidacy::new(10000, 0),
+            Candidacy::new(500, 0),
+        ];
+
+        let weighted = compute_penrose(&mut states, 100, Method::SAINTELAGUE).unwrap();
+
+        assert_eq!(
+            weighted.iter().map(|w| w.seats).collect::<Vec<_>>(),
+            vec![45, 35, 16, 4]
+        );
+        assert_eq!(
+            states.iter().map(|s| s.get_seats()).collect::<Vec<_>>(),
+            vec![45, 35, 16, 4]
+        );
+        assert!((weighted[3].weight - 22.36068).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_power_one_is_proportional() {
+        let mut states = vec![Candidacy::new(750, 0), Candidacy::new(250, 0)];
+
+        let weighted = compute_power_apportionment(&mut states, 8, Method::DHONDT, 1.0).unwrap();
+        assert_eq!(weighted[0].seats, 6);
+        assert_eq!(weighted[1].seats, 2);
+    }
+
+    #[test]
+    fn test_empty_results() {
+        let mut states: Vec<Candidacy> = vec![];
+
+        assert_eq!(
+            compute_penrose(&mut states, 10, Method::DHONDT),
+            Err("EMPTY_RESULTS")
+        );
+    }
+}
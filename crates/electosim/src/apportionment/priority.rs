@@ -0,0 +1,171 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    utils::clear_results,
+};
+
+/// A seat of the priority list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrioritySeat {
+    /// The position of the seat in the chamber (ex: 51 for the first seat after the guaranteed ones in the US House).
+    pub seat: u16,
+    /// The index of the state receiving the seat.
+    pub state: usize,
+    /// The number of seats of the state with this one (ex: 2 for its second seat).
+    pub state_seats: u16,
+    /// The priority value of the seat, `population / sqrt(n * (n - 1))` for the `n`-th seat of the state.
+    pub priority: f64,
+}
+
+/// The Huntington-Hill priority list of an apportionment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriorityList {
+    /// The seats assigned after the guaranteed ones, in priority order.
+    pub awarded: Vec<PrioritySeat>,
+    /// The first seats that would be assigned in a larger chamber, identifying the first states to miss out.
+    pub next: Vec<PrioritySeat>,
+}
+
+fn priority_value(population: u32, seats: u16) -> f64 {
+    population as f64 / ((seats as f64) * (seats as f64 + 1.0)).sqrt()
+}
+
+/// Apportions seats with the Huntington-Hill method and guaranteed minimums, as in the US House of Representatives.
+///
+/// Every state receives `minimum` seats and the rest are assigned in order of priority value, which is the
+/// quotient used by [compute_huntington_hill][crate::methods::divisor::compute_huntington_hill]. Priority values are
+/// computed in double precision, since close calls between states can be decided by a few people.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of states, where votes are the population.
+/// * `seats` - The number of seats of the chamber.
+/// * `minimum` - The seats guaranteed to every state.
+/// * `next` - The number of seats to list after the last awarded one.
+///
+/// # Returns
+///
+/// The priority list, with the seats of each state also set on `results`.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::apportionment::compute_priority_list;
+/// use electosim::*;
+///
+/// let mut states = vec![candidacy!(600_000), candidacy!(300_000), candidacy!(100_000)];
+///
+/// let list = compute_priority_list(&mut states, 10, 1, 1).unwrap();
+/// assert_eq!(list.awarded.first().unwrap().seat, 4);
+/// assert_eq!(list.awarded.last().unwrap().seat, 10);
+/// assert_eq!(list.next[0].seat, 11);
+/// ```
+pub fn compute_priority_list<T>(
+    results: &mut [T],
+    seats: u16,
+    minimum: u16,
+    next: usize,
+) -> Result<PriorityList, &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+
+    if results.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+
+    let guaranteed = minimum as u32 * results.len() as u32;
+    if guaranteed > seats as u32 {
+        return Err("NOT_ENOUGH_SEATS");
+    }
+
+    let mut state_seats = vec![minimum; results.len()];
+    let mut list = (guaranteed as usize..seats as usize + next).map(|position| {
+        let (state, priority) = results
+            .iter()
+            .zip(&state_seats)
+            .map(|(c, &s)| priority_value(c.get_votes(), s))
+            .enumerate()
+            .fold((0, f64::NEG_INFINITY), |best, (idx, p)| match p > best.1 {
+                true => (idx, p),
+                false => best,
+            });
+
+        state_seats[state] += 1;
+        PrioritySeat {
+            seat: position as u16 + 1,
+            state,
+            state_seats: state_seats[state],
+            priority,
+        }
+    });
+
+    let awarded = list
+        .by_ref()
+        .take(seats as usize - guaranteed as usize)
+        .collect::<Vec<_>>();
+    let next = list.collect::<Vec<_>>();
+
+    results.iter_mut().for_each(|r| r.set_seats(minimum));
+    awarded
+        .iter()
+        .for_each(|s| results[s.state].increase_seats(1));
+
+    Ok(PriorityList { awarded, next })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{methods::divisor::compute_huntington_hill, models::Candidacy};
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_priority_list() {
+        let mut states = vec![
+            Candidacy::new(39_538_223, 0),
+            Candidacy::new(29_145_505, 0),
+            Candidacy::new(6_177_224, 0),
+            Candidacy::new(576_851, 0),
+        ];
+
+        let list = compute_priority_list(&mut states, 20, 1, 2).unwrap();
+
+        assert_eq!(list.awarded.len(), 16);
+        assert_eq!(list.awarded[0].seat, 5);
+        assert_eq!(list.awarded[0].state, 0);
+        assert_eq!(list.awarded[0].state_seats, 2);
+        assert!((list.awarded[0].priority - 39_538_223.0 / 2f64.sqrt()).abs() < 1e-6);
+        assert!(list
+            .awarded
+            .windows(2)
+            .all(|w| w[0].priority >= w[1].priority));
+        assert!(list.next[0].priority <= list.awarded[15].priority);
+        assert_eq!(
+            list.next.iter().map(|s| s.seat).collect::<Vec<_>>(),
+            vec![21, 22]
+        );
+
+        let priority_seats = seats(&states);
+        compute_huntington_hill(&mut states, 20).unwrap();
+        assert_eq!(priority_seats, seats(&states));
+    }
+
+    #[test]
+    fn test_minimum() {
+        let mut states = vec![Candidacy::new(1000, 0), Candidacy::new(10, 0)];
+
+        let list = compute_priority_list(&mut states, 6, 2, 0).unwrap();
+        assert_eq!(seats(&states), vec![4, 2]);
+        assert_eq!(list.awarded.len(), 2);
+        assert!(list.next.is_empty());
+
+        assert_eq!(
+            compute_priority_list(&mut states, 3, 2, 0),
+            Err("NOT_ENOUGH_SEATS")
+        );
+    }
+}
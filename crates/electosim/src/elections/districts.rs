@@ -0,0 +1,314 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    elections::{combine_totals, swing_elections, PartyTotal, SwingModel},
+    models::Candidacy,
+    threshold::{add_to_national, ThresholdScope},
+    SimpleElection,
+};
+
+/// A district of a [MultiDistrictElection].
+#[derive(Debug, Clone)]
+pub struct District {
+    /// The name of the district (ex: "Madrid").
+    pub name: String,
+    /// The candidacies, magnitude, method and threshold of the district.
+    pub election: SimpleElection,
+}
+
+/// An election held in several districts, as the Spanish Congress with its 52 provinces.
+///
+/// Each district has its own candidacies, magnitude, method and threshold. Candidacies of the same party share their
+/// [id][crate::models::Candidacy::id] across districts, which is used to aggregate the national results and to check
+/// national thresholds.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::elections::MultiDistrictElection;
+/// use electosim::*;
+///
+/// let madrid = election!(
+///     vec![candidacy!(500).with_id("A"), candidacy!(300).with_id("B"), candidacy!(200).with_id("C")],
+///     5,
+///     Method::DHONDT,
+///     0.03
+/// );
+/// let soria = election!(
+///     vec![candidacy!(60).with_id("B"), candidacy!(40).with_id("A")],
+///     2,
+///     Method::DHONDT,
+///     0.03
+/// );
+///
+/// let mut election = MultiDistrictElection::new()
+///     .with_district("Madrid", madrid)
+///     .with_district("Soria", soria);
+/// election.compute().unwrap();
+///
+/// let totals = election.totals().unwrap();
+/// assert_eq!(totals.iter().map(|t| (t.id.as_str(), t.votes, t.seats)).collect::<Vec<_>>(), vec![("A", 540, 4), ("B", 360, 2), ("C", 200, 1)]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiDistrictElection {
+    /// The districts of the election.
+    pub districts: Vec<District>,
+}
+
+impl MultiDistrictElection {
+    /// Creates an election without districts.
+    pub fn new() -> Self {
+        MultiDistrictElection { districts: vec![] }
+    }
+
+    /// Adds a district.
+    pub fn with_district(mut self, name: impl Into<String>, election: SimpleElection) -> Self {
+        self.districts.push(District {
+            name: name.into(),
+            election,
+        });
+        self
+    }
+
+    /// Returns the district with the given name, if any.
+    pub fn district(&self, name: &str) -> Option<&District> {
+        self.districts.iter().find(|d| d.name == name)
+    }
+
+    /// Returns the number of seats of the chamber, the sum of the district magnitudes.
+    pub fn seats(&self) -> u16 {
+        self.districts.iter().map(|d| d.election.seats).sum()
+    }
+
+    /// Aggregates the candidacies of every district per party, as checked by national thresholds.
+    ///
+    /// Votes and constituency wins are added. A party is a coalition if it is in any district, and its threshold
+    /// override is the first one found.
+    ///
+    /// # Returns
+    ///
+    /// The national candidacies keyed by party id, `Err("MISSING_ID")` if a candidacy has no id,
+    /// `Err("DUPLICATE_ID")` if two candidacies of the same district share an id, or `Err("OUT_OF_RANGE")` if the
+    /// national votes of a party do not fit in a `u32`.
+    pub fn national_candidacies(&self) -> Result<BTreeMap<String, Candidacy>, &'static str> {
+        let mut national: BTreeMap<String, Candidacy> = BTreeMap::new();
+
+        for district in self.districts.iter() {
+            let mut ids = BTreeSet::new();
+            for c in district.election.results.iter() {
+                let id = c.id().ok_or("MISSING_ID")?;
+                if !ids.insert(id) {
+                    return Err("DUPLICATE_ID");
+                }
+
+                let n = national
+                    .entry(id.to_string())
+                    .or_insert_with(|| Candidacy::new(0, 0).with_id(id));
+                add_to_national(n, c)?;
+            }
+        }
+
+        Ok(national)
+    }
+
+    /// Computes every district.
+    ///
+    /// District thresholds are checked with the votes of the district. National thresholds are checked with the
+    /// [national candidacies][MultiDistrictElection::national_candidacies], over the sum of the base votes of every district.
+    pub fn compute(&mut self) -> Result<(), &'static str> {
+        let national_scope = self.districts.iter().any(|d| {
+            d.election
+                .threshold
+                .as_ref()
+                .is_some_and(|t| t.scope == ThresholdScope::National)
+        });
+
+        let national = match national_scope {
+            true => self.national_candidacies()?,
+            false => BTreeMap::new(),
+        };
+        let national_base: u64 = self
+            .districts
+            .iter()
+            .map(|d| {
+                d.election
+                    .effective_threshold()
+                    .base_votes(d.election.total_votes())
+            })
+            .sum();
+
+        for district in self.districts.iter_mut() {
+            let threshold = district.election.effective_threshold();
+
+            let qualifications = match threshold.scope {
+                ThresholdScope::District => threshold.qualifications(&district.election.results),
+                ThresholdScope::National => district
+                    .election
+                    .results
+                    .iter()
+                    .map(|c| {
+                        let n = c.id().and_then(|id| national.get(id)).ok_or("MISSING_ID")?;
+                        Ok(threshold.qualification(n, national_base))
+                    })
+                    .collect::<Result<Vec<_>, &'static str>>()?,
+            };
+
+            district
+                .election
+                .compute_with_qualifications(qualifications)?;
+        }
+
+        Ok(())
+    }
+
+    /// Projects the votes of every district to the `target` national shares and recomputes the seats.
+    ///
+    /// See [swing_elections] for the details of the projection.
+    pub fn swing(
+        &mut self,
+        model: SwingModel,
+        target: &BTreeMap<String, f32>,
+    ) -> Result<(), &'static str> {
+        swing_elections(
+            self.districts.iter_mut().map(|d| &mut d.election),
+            model,
+            target,
+        )?;
+        self.compute()
+    }
+
+    /// Combines the votes and seats of every district per party.
+    ///
+    /// # Returns
+    ///
+    /// The national totals sorted by party id, or `Err("MISSING_ID")` if a candidacy has no id.
+    pub fn totals(&self) -> Result<Vec<PartyTotal>, &'static str> {
+        combine_totals(self.districts.iter().map(|d| &d.election))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interface::WithSeats,
+        threshold::{Threshold, ThresholdQualification},
+        Method,
+    };
+
+    fn district(results: Vec<(&str, u32)>, seats: u16) -> SimpleElection {
+        SimpleElection::new(
+            results
+                .into_iter()
+                .map(|(id, votes)| Candidacy::new(votes, 0).with_id(id))
+                .collect(),
+            seats,
+            Method::DHONDT,
+        )
+    }
+
+    #[test]
+    fn test_national_threshold() {
+        let threshold = Threshold::percentage(0.06).national();
+        let mut election = MultiDistrictElection::new()
+            .with_district(
+                "North",
+                district(vec![("A", 500), ("B", 400), ("C", 100)], 10)
+                    .with_threshold(threshold.clone()),
+            )
+            .with_district(
+                "South",
+                district(vec![("A", 600), ("B", 390), ("C", 10)], 10).with_threshold(threshold),
+            );
+
+        election.compute().unwrap();
+
+        // C has 10% in the North but 5.5% nationally.
+        let north = &election.district("North").unwrap().election;
+        assert_eq!(north.results[2].get_seats(), 0);
+        assert_eq!(north.qualifications[2], ThresholdQualification::Excluded);
+
+        let totals = election.totals().unwrap();
+        assert_eq!(
+            totals.iter().map(|t| t.seats).collect::<Vec<_>>(),
+            vec![12, 8, 0]
+        );
+        assert_eq!(election.seats(), 20);
+    }
+
+    #[test]
+    fn test_district_methods() {
+        let mut election = MultiDistrictElection::new()
+            .with_district("One", district(vec![("A", 600), ("B", 400)], 1))
+            .with_district("Two", {
+                let mut d = district(vec![("B", 600), ("A", 400)], 5);
+                d.method = Method::HARE;
+                d
+            });
+
+        election.compute().unwrap();
+
+        let totals = election.totals().unwrap();
+        assert_eq!(
+            totals[0],
+            PartyTotal {
+                id: "A".to_string(),
+                votes: 1000,
+                seats: 3
+            }
+        );
+        assert_eq!(
+            totals[1],
+            PartyTotal {
+                id: "B".to_string(),
+                votes: 1000,
+                seats: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_id() {
+        let mut election = MultiDistrictElection::new().with_district(
+            "One",
+            SimpleElection::new(vec![Candidacy::new(10, 0)], 1, Method::DHONDT)
+                .with_threshold(Threshold::percentage(0.05).national()),
+        );
+
+        assert_eq!(election.compute(), Err("MISSING_ID"));
+    }
+
+    #[test]
+    fn test_duplicate_id() {
+        let mut election = MultiDistrictElection::new().with_district(
+            "One",
+            district(vec![("A", 600), ("A", 400)], 1)
+                .with_threshold(Threshold::percentage(0.05).national()),
+        );
+
+        assert_eq!(election.national_candidacies().err(), Some("DUPLICATE_ID"));
+        assert_eq!(election.compute(), Err("DUPLICATE_ID"));
+    }
+
+    #[test]
+    fn test_national_votes_out_of_range() {
+        let election = MultiDistrictElection::new()
+            .with_district("One", district(vec![("A", 3_000_000_000)], 1))
+            .with_district("Two", district(vec![("A", 2_000_000_000)], 1));
+
+        assert_eq!(election.national_candidacies().err(), Some("OUT_OF_RANGE"));
+    }
+
+    #[test]
+    fn test_empty_district() {
+        let mut election = MultiDistrictElection::new()
+            .with_district(
+                "One",
+                district(vec![("A", 600), ("B", 400)], 1)
+                    .with_threshold(Threshold::percentage(0.05).national()),
+            )
+            .with_district("Two", district(vec![], 1));
+
+        assert_eq!(election.compute(), Err("EMPTY_RESULTS"));
+    }
+}
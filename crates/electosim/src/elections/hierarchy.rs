@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    interface::WithSeats, methods::Method, models::Candidacy, threshold::Threshold, SimpleElection,
+};
+
+/// The seats elected at a level of a [ResultsNode] tree.
+#[derive(Debug, Clone)]
+pub struct SeatRule {
+    /// The number of seats elected with the votes of the node.
+    pub seats: u16,
+    /// The method used to distribute the seats.
+    pub method: Method,
+    /// The threshold of the node, if any.
+    pub threshold: Option<Threshold>,
+}
+
+impl SeatRule {
+    /// Creates a rule without threshold.
+    pub fn new(seats: u16, method: Method) -> Self {
+        SeatRule {
+            seats,
+            method,
+            threshold: None,
+        }
+    }
+
+    /// Sets the threshold checked with the votes of the node.
+    pub fn with_threshold(mut self, threshold: Threshold) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+}
+
+/// A node of a hierarchical results tree (ex: polling station, municipality, district, nation).
+///
+/// Leaves hold the votes of each party. The votes of the other nodes are the sum of their children, and the votes
+/// published for them, if any, are only used to validate the tree. Seats are computed at the nodes with a [SeatRule]
+/// and added up in their ancestors.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::elections::{ResultsNode, SeatRule};
+/// use electosim::*;
+///
+/// let mut nation = ResultsNode::node("Nation", vec![
+///     ResultsNode::node("North", vec![
+///         ResultsNode::leaf("Station 1", [("A", 300), ("B", 100)]),
+///         ResultsNode::leaf("Station 2", [("A", 100), ("B", 200)]),
+///     ])
+///     .with_votes([("A", 400), ("B", 300)])
+///     .with_rule(SeatRule::new(7, Method::DHONDT)),
+///     ResultsNode::node("South", vec![
+///         ResultsNode::leaf("Station 3", [("B", 500), ("C", 100)]),
+///     ])
+///     .with_rule(SeatRule::new(3, Method::DHONDT)),
+/// ]);
+///
+/// nation.validate().unwrap();
+/// nation.compute().unwrap();
+///
+/// assert_eq!(nation.votes()["B"], 800);
+/// assert_eq!(nation.find(&["North"]).unwrap().seats()["A"], 4);
+/// assert_eq!(nation.seats()["B"], 6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResultsNode {
+    /// The name of the node.
+    pub name: String,
+    /// The votes of each party: counted for leaves, published for the other nodes.
+    pub reported: BTreeMap<String, u32>,
+    /// The children of the node.
+    pub children: Vec<ResultsNode>,
+    /// The seats elected at this node, if any.
+    pub rule: Option<SeatRule>,
+    seats: BTreeMap<String, u16>,
+}
+
+impl ResultsNode {
+    /// Creates a leaf with the votes of each party.
+    pub fn leaf<I, S>(name: impl Into<String>, votes: I) -> Self
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        ResultsNode {
+            name: name.into(),
+            reported: BTreeMap::new(),
+            children: vec![],
+            rule: None,
+            seats: BTreeMap::new(),
+        }
+        .with_votes(votes)
+    }
+
+    /// Creates a node aggregating its children.
+    pub fn node(name: impl Into<String>, children: Vec<ResultsNode>) -> Self {
+        ResultsNode {
+            name: name.into(),
+            reported: BTreeMap::new(),
+            children,
+            rule: None,
+            seats: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the votes of each party: counted for leaves, published for the other nodes.
+    pub fn with_votes<I, S>(mut self, votes: I) -> Self
+    where
+        I: IntoIterator<Item = (S, u32)>,
+        S: Into<String>,
+    {
+        self.reported = votes.into_iter().map(|(id, v)| (id.into(), v)).collect();
+        self
+    }
+
+    /// Sets the seats elected at this node.
+    pub fn with_rule(mut self, rule: SeatRule) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    /// Checks whether the node has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns the votes of each party: the counted votes of a leaf or the sum of the children.
+    pub fn votes(&self) -> BTreeMap<String, u32> {
+        if self.is_leaf() {
+            return self.reported.clone();
+        }
+
+        let mut votes = BTreeMap::new();
+        for (id, v) in self.children.iter().flat_map(|c| c.votes()) {
+            *votes.entry(id).or_insert(0) += v;
+        }
+        votes
+    }
+
+    /// Returns the total votes of the node.
+    pub fn total_votes(&self) -> u32 {
+        self.votes().values().sum()
+    }
+
+    /// Returns the share of the votes of each party.
+    pub fn shares(&self) -> BTreeMap<String, f32> {
+        let votes = self.votes();
+        let total = votes.values().sum::<u32>() as f32;
+
+        votes
+            .into_iter()
+            .map(|(id, v)| (id, if total > 0.0 { v as f32 / total } else { 0.0 }))
+            .collect()
+    }
+
+    /// Returns the seats of each party computed at this node or its descendants.
+    pub fn seats(&self) -> &BTreeMap<String, u16> {
+        &self.seats
+    }
+
+    /// Returns the candidacies of the node with their votes and seats, sorted by party id.
+    ///
+    /// They can be used with the [metrics][crate::metrics] of the library.
+    pub fn candidacies(&self) -> Vec<Candidacy> {
+        self.votes()
+            .into_iter()
+            .map(|(id, v)| {
+                let seats = self.seats.get(&id).copied().unwrap_or(0);
+                Candidacy::new(v, seats).with_id(id)
+            })
+            .collect()
+    }
+
+    /// Finds a descendant by the names of the nodes on its path, starting from the children of this node.
+    pub fn find(&self, path: &[&str]) -> Option<&ResultsNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((name, rest)) => self
+                .children
+                .iter()
+                .find(|c| c.name == *name)
+                .and_then(|c| c.find(rest)),
+        }
+    }
+
+    /// Returns the paths of the nodes whose published votes differ from the sum of their children.
+    pub fn mismatches(&self) -> Vec<Vec<String>> {
+        let mut mismatches = vec![];
+
+        if !self.is_leaf() && !self.reported.is_empty() && self.reported != self.votes() {
+            mismatches.push(vec![self.name.clone()]);
+        }
+
+        for child in self.children.iter() {
+            for mut path in child.mismatches() {
+                path.insert(0, self.name.clone());
+                mismatches.push(path);
+            }
+        }
+
+        mismatches
+    }
+
+    /// Checks that the published votes of every node are the sum of their children.
+    ///
+    /// # Returns
+    ///
+    /// `Err("VOTES_MISMATCH")` if any node differs. See [ResultsNode::mismatches] to find them.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.mismatches().is_empty() {
+            true => Ok(()),
+            false => Err("VOTES_MISMATCH"),
+        }
+    }
+
+    /// Computes the seats of every node with a [SeatRule] and adds them up in their ancestors.
+    pub fn compute(&mut self) -> Result<(), &'static str> {
+        self.seats.clear();
+        for child in self.children.iter_mut() {
+            child.compute()?;
+        }
+
+        match &self.rule {
+            Some(rule) => {
+                let mut election = SimpleElection::new(self.candidacies(), rule.seats, rule.method);
+                election.threshold = rule.threshold.clone();
+                election.compute()?;
+
+                self.seats = election
+                    .results
+                    .iter()
+                    .filter(|c| c.get_seats() > 0)
+                    .map(|c| (c.id().unwrap_or_default().to_string(), c.get_seats()))
+                    .collect();
+            }
+            None => {
+                for (id, s) in self.children.iter().flat_map(|c| c.seats.iter()) {
+                    *self.seats.entry(id.clone()).or_insert(0) += s;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::gallagher_index;
+
+    fn tree() -> ResultsNode {
+        ResultsNode::node(
+            "Nation",
+            vec![
+                ResultsNode::node(
+                    "North",
+                    vec![
+                        ResultsNode::node(
+                            "Town",
+                            vec![
+                                ResultsNode::leaf("1", [("A", 120), ("B", 80)]),
+                                ResultsNode::leaf("2", [("A", 60), ("C", 40)]),
+                            ],
+                        )
+                        .with_votes([("A", 180), ("B", 80), ("C", 40)]),
+                        ResultsNode::leaf("Village", [("A", 20), ("B", 100)]),
+                    ],
+                )
+                .with_rule(SeatRule::new(4, Method::SAINTELAGUE)),
+                ResultsNode::node(
+                    "South",
+                    vec![ResultsNode::leaf("3", [("B", 300), ("C", 100)])],
+                )
+                .with_rule(SeatRule::new(4, Method::SAINTELAGUE)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let mut nation = tree();
+
+        assert_eq!(nation.total_votes(), 820);
+        assert_eq!(nation.votes()["B"], 480);
+        assert_eq!(nation.find(&["North", "Town"]).unwrap().total_votes(), 300);
+        assert!((nation.find(&["South"]).unwrap().shares()["B"] - 0.75).abs() < 1e-6);
+        assert!(nation.find(&["East"]).is_none());
+
+        nation.compute().unwrap();
+
+        let north = nation.find(&["North"]).unwrap();
+        assert_eq!(north.seats().get("A"), Some(&2));
+        assert_eq!(north.seats().get("B"), Some(&2));
+        assert_eq!(nation.seats().get("B"), Some(&5));
+        assert_eq!(nation.seats().values().sum::<u16>(), 8);
+
+        let candidacies = nation.candidacies();
+        assert_eq!(candidacies[1].id(), Some("B"));
+        assert!(gallagher_index(&candidacies) > 0.0);
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut nation = tree();
+        assert_eq!(nation.validate(), Ok(()));
+
+        nation.children[0].children[0]
+            .reported
+            .insert("A".to_string(), 170);
+        assert_eq!(nation.validate(), Err("VOTES_MISMATCH"));
+        assert_eq!(
+            nation.mismatches(),
+            vec![vec![
+                "Nation".to_string(),
+                "North".to_string(),
+                "Town".to_string()
+            ]]
+        );
+    }
+}
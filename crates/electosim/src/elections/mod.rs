@@ -0,0 +1,17 @@
+//! # Elections
+//! Elections composed of several contests, each computed as a [SimpleElection][crate::SimpleElection],
+//! whose results are combined per party with the [id][crate::models::Candidacy::id] of the candidacies.
+//!
+//! Polls can be projected into the districts of an election with the [SwingModel] swing models.
+//!
+//! Results reported at several levels (polling station, municipality, district) can be aggregated with a [ResultsNode] tree.
+
+mod districts;
+mod hierarchy;
+mod reserved;
+mod swing;
+
+pub use districts::*;
+pub use hierarchy::*;
+pub use reserved::*;
+pub use swing::*;
@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    interface::{WithSeats, WithVotes},
+    SimpleElection,
+};
+
+/// A contest for seats reserved to a minority or a special electorate.
+pub struct ReservedContest {
+    /// The name of the contest (ex: "Serbian minority").
+    pub name: String,
+    /// The candidacies, seats, method and threshold of the contest.
+    pub election: SimpleElection,
+}
+
+/// The combined results of a party.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartyTotal {
+    /// The identifier of the party.
+    pub id: String,
+    /// The votes of the party in all the contests.
+    pub votes: u32,
+    /// The seats of the party in all the contests.
+    pub seats: u16,
+}
+
+/// Combines the votes and seats of the candidacies of several elections per party.
+///
+/// # Returns
+///
+/// The totals sorted by party id, or `Err("MISSING_ID")` if a candidacy has no id.
+pub(crate) fn combine_totals<'a>(
+    elections: impl Iterator<Item = &'a SimpleElection>,
+) -> Result<Vec<PartyTotal>, &'static str> {
+    let mut totals: BTreeMap<&str, (u32, u16)> = BTreeMap::new();
+
+    for candidacy in elections.flat_map(|e| e.results.iter()) {
+        let id = candidacy.id().ok_or("MISSING_ID")?;
+        let total = totals.entry(id).or_default();
+        total.0 += candidacy.get_votes();
+        total.1 += candidacy.get_seats();
+    }
+
+    Ok(totals
+        .into_iter()
+        .map(|(id, (votes, seats))| PartyTotal {
+            id: id.to_string(),
+            votes,
+            seats,
+        })
+        .collect())
+}
+
+/// An election with a general allocation and contests for reserved seats, as in Croatia, Slovenia or New Zealand.
+///
+/// Each contest is computed separately with its own candidacies, seats and method, and the results are combined per party.
+///
+/// # Example (Croatia)
+///
+/// ```rust
+/// use electosim::elections::ReservedSeatsElection;
+/// use electosim::*;
+///
+/// let general = election!(
+///     vec![candidacy!(6000).with_id("HDZ"), candidacy!(4000).with_id("SDP")],
+///     10,
+///     Method::DHONDT,
+///     0.05
+/// );
+/// let serbian = election!(
+///     vec![candidacy!(400).with_id("SDSS"), candidacy!(100).with_id("HDZ")],
+///     3,
+///     Method::DHONDT
+/// );
+///
+/// let mut election = ReservedSeatsElection::new(general).with_contest("Serbian minority", serbian);
+/// election.compute().unwrap();
+///
+/// let totals = election.totals().unwrap();
+/// assert_eq!(totals.iter().map(|t| (t.id.as_str(), t.seats)).collect::<Vec<_>>(), vec![("HDZ", 6), ("SDP", 4), ("SDSS", 3)]);
+/// assert_eq!(election.seats(), 13);
+/// ```
+pub struct ReservedSeatsElection {
+    /// The general allocation.
+    pub general: SimpleElection,
+    /// The contests for reserved seats.
+    pub reserved: Vec<ReservedContest>,
+}
+
+impl ReservedSeatsElection {
+    /// Creates an election with a general allocation and no reserved seats.
+    pub fn new(general: SimpleElection) -> Self {
+        ReservedSeatsElection {
+            general,
+            reserved: vec![],
+        }
+    }
+
+    /// Adds a contest for reserved seats.
+    pub fn with_contest(mut self, name: impl Into<String>, election: SimpleElection) -> Self {
+        self.reserved.push(ReservedContest {
+            name: name.into(),
+            election,
+        });
+        self
+    }
+
+    /// Returns the number of seats of the chamber, general and reserved.
+    pub fn seats(&self) -> u16 {
+        self.general.seats + self.reserved.iter().map(|c| c.election.seats).sum::<u16>()
+    }
+
+    /// Computes the general allocation and every contest.
+    pub fn compute(&mut self) -> Result<(), &'static str> {
+        self.general.compute()?;
+        self.reserved
+            .iter_mut()
+            .try_for_each(|c| c.election.compute())
+    }
+
+    /// Combines the votes and seats of every contest per party.
+    ///
+    /// # Returns
+    ///
+    /// The totals sorted by party id, or `Err("MISSING_ID")` if a candidacy has no id.
+    pub fn totals(&self) -> Result<Vec<PartyTotal>, &'static str> {
+        combine_totals(
+            std::iter::once(&self.general).chain(self.reserved.iter().map(|c| &c.election)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::Candidacy, Method};
+
+    #[test]
+    fn test_reserved_seats() {
+        let general = SimpleElection::new(
+            vec![
+                Candidacy::new(5000, 0).with_id("A"),
+                Candidacy::new(3000, 0).with_id("B"),
+                Candidacy::new(200, 0).with_id("C"),
+            ],
+            8,
+            Method::DHONDT,
+        );
+        let maori = SimpleElection::new(
+            vec![
+                Candidacy::new(700, 0).with_id("C"),
+                Candidacy::new(300, 0).with_id("B"),
+            ],
+            1,
+            Method::WINNERTAKESALL,
+        );
+        let italian =
+            SimpleElection::new(vec![Candidacy::new(90, 0).with_id("D")], 1, Method::DHONDT);
+
+        let mut election = ReservedSeatsElection::new(general)
+            .with_contest("Maori", maori)
+            .with_contest("Italian minority", italian);
+        election.compute().unwrap();
+
+        assert_eq!(election.seats(), 10);
+        assert_eq!(
+            election.totals().unwrap(),
+            vec![
+                PartyTotal {
+                    id: "A".to_string(),
+                    votes: 5000,
+                    seats: 5
+                },
+                PartyTotal {
+                    id: "B".to_string(),
+                    votes: 3300,
+                    seats: 3
+                },
+                PartyTotal {
+                    id: "C".to_string(),
+                    votes: 900,
+                    seats: 1
+                },
+                PartyTotal {
+                    id: "D".to_string(),
+                    votes: 90,
+                    seats: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_id() {
+        let general = SimpleElection::new(vec![Candidacy::new(10, 0)], 1, Method::DHONDT);
+        let mut election = ReservedSeatsElection::new(general);
+
+        election.compute().unwrap();
+        assert_eq!(election.totals(), Err("MISSING_ID"));
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    elections::combine_totals, interface::WithVotes, utils::distribute_votes, SimpleElection,
+};
+
+/// How a change of the national share of a party is projected into the districts.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SwingModel {
+    /// The change of the national share is added to the district share.
+    Uniform,
+    /// The district share is multiplied by the ratio of the national shares.
+    Proportional,
+    /// The change of the national share is added to the log-odds of the district share.
+    Logit,
+}
+
+impl SwingModel {
+    /// Projects a district share, given the baseline and target national shares of the party.
+    ///
+    /// The result may be negative with [SwingModel::Uniform] and must be clamped and normalized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use electosim::elections::SwingModel;
+    ///
+    /// assert!((SwingModel::Uniform.apply(0.30, 0.40, 0.45) - 0.35).abs() < 1e-6);
+    /// assert!((SwingModel::Proportional.apply(0.30, 0.40, 0.50) - 0.375).abs() < 1e-6);
+    /// assert!((SwingModel::Logit.apply(0.40, 0.40, 0.50) - 0.50).abs() < 1e-6);
+    /// ```
+    pub fn apply(&self, share: f32, baseline: f32, target: f32) -> f32 {
+        let (share, baseline, target) = (share as f64, baseline as f64, target as f64);
+
+        let projected = match self {
+            SwingModel::Uniform => share + target - baseline,
+            SwingModel::Proportional => match baseline > 0.0 {
+                true => share * target / baseline,
+                false => share,
+            },
+            SwingModel::Logit => {
+                if share <= 0.0 || share >= 1.0 || baseline <= 0.0 || baseline >= 1.0 {
+                    share
+                } else if target <= 0.0 {
+                    0.0
+                } else if target >= 1.0 {
+                    1.0
+                } else {
+                    let odds = share / (1.0 - share) * (target / (1.0 - target))
+                        / (baseline / (1.0 - baseline));
+                    odds / (1.0 + odds)
+                }
+            }
+        };
+
+        projected as f32
+    }
+}
+
+/// Projects the votes of several elections to the `target` national shares of each party, keyed by party id.
+///
+/// The baseline national shares are computed from the elections. In every election, the shares of the parties are
+/// projected with `model`, clamped at zero and normalized, and the total votes of the election are distributed with
+/// [distribute_votes], keeping the total exact. Parties without a target keep their share before the normalization.
+/// The seats of every election are then recomputed with its method and threshold.
+///
+/// # Returns
+///
+/// `Err("MISSING_ID")` if a candidacy has no id, or the error of computing an election.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use electosim::elections::{swing_elections, SwingModel};
+/// use electosim::interface::WithSeats;
+/// use electosim::*;
+///
+/// let mut districts = vec![
+///     election!(vec![candidacy!(600).with_id("A"), candidacy!(400).with_id("B")], 5, Method::DHONDT),
+///     election!(vec![candidacy!(200).with_id("A"), candidacy!(800).with_id("B")], 5, Method::DHONDT),
+/// ];
+///
+/// // A goes from 40% to 50% nationally.
+/// let target = BTreeMap::from([("A".to_string(), 0.5), ("B".to_string(), 0.5)]);
+/// swing_elections(districts.iter_mut(), SwingModel::Uniform, &target).unwrap();
+///
+/// assert_eq!(districts[0].results[0].get_votes(), 700);
+/// assert_eq!(districts[1].results[0].get_votes(), 300);
+/// assert_eq!(districts[0].results[0].get_seats(), 4);
+/// ```
+pub fn swing_elections<'a>(
+    elections: impl Iterator<Item = &'a mut SimpleElection>,
+    model: SwingModel,
+    target: &BTreeMap<String, f32>,
+) -> Result<(), &'static str> {
+    let mut elections = elections.collect::<Vec<_>>();
+
+    let totals = combine_totals(elections.iter().map(|e| &**e))?;
+    let national_votes = totals.iter().map(|t| t.votes).sum::<u32>() as f32;
+    let baseline = totals
+        .iter()
+        .map(|t| (t.id.as_str(), t.votes as f32 / national_votes))
+        .collect::<BTreeMap<_, _>>();
+
+    for election in elections.iter_mut() {
+        let total = election.total_votes();
+        if total == 0 {
+            election.compute()?;
+            continue;
+        }
+
+        let projected = election
+            .results
+            .iter()
+            .map(|c| {
+                let id = c.id().ok_or("MISSING_ID")?;
+                let share = c.get_votes() as f32 / total as f32;
+                let projected = match target.get(id) {
+                    Some(&t) => model.apply(share, baseline[id], t),
+                    None => share,
+                };
+                Ok(projected as f64)
+            })
+            .collect::<Result<Vec<_>, &'static str>>()?;
+
+        for (c, votes) in election
+            .results
+            .iter_mut()
+            .zip(distribute_votes(&projected, total))
+        {
+            c.set_votes(votes);
+        }
+        election.compute()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elections::MultiDistrictElection, interface::WithSeats, models::Candidacy, Method,
+    };
+
+    fn district(votes: [u32; 3]) -> SimpleElection {
+        SimpleElection::new(
+            ["A", "B", "C"]
+                .iter()
+                .zip(votes)
+                .map(|(id, v)| Candidacy::new(v, 0).with_id(*id))
+                .collect(),
+            4,
+            Method::DHONDT,
+        )
+    }
+
+    fn votes(election: &SimpleElection) -> Vec<u32> {
+        election.results.iter().map(|c| c.get_votes()).collect()
+    }
+
+    #[test]
+    fn test_uniform_clamps_at_zero() {
+        // National shares: A 50%, B 30%, C 20%.
+        let mut districts = [district([500, 450, 50]), district([500, 150, 350])];
+        let target = BTreeMap::from([("C".to_string(), 0.05), ("A".to_string(), 0.65)]);
+
+        swing_elections(districts.iter_mut(), SwingModel::Uniform, &target).unwrap();
+
+        // C loses 15 points, more than its share in the first district, where the rest is normalized.
+        assert_eq!(votes(&districts[0]), vec![591, 409, 0]);
+        assert_eq!(votes(&districts[1]), vec![650, 150, 200]);
+    }
+
+    #[test]
+    fn test_proportional_and_logit() {
+        let mut proportional = vec![district([500, 450, 50]), district([500, 150, 350])];
+        let mut logit = proportional.clone();
+        let target = BTreeMap::from([("C".to_string(), 0.1)]);
+
+        swing_elections(proportional.iter_mut(), SwingModel::Proportional, &target).unwrap();
+        // Exact votes: 512.8, 461.5, 25.6 and 606.1, 181.8, 212.1.
+        assert_eq!(votes(&proportional[0]), vec![513, 461, 26]);
+        assert_eq!(votes(&proportional[1]), vec![606, 182, 212]);
+
+        swing_elections(logit.iter_mut(), SwingModel::Logit, &target).unwrap();
+        // Log-odds swings are smaller where the party is weak.
+        assert_eq!(votes(&logit[0]), vec![514, 463, 23]);
+        assert_eq!(votes(&logit[1]), vec![593, 178, 229]);
+
+        // Every district keeps its total votes, and its seats are recomputed.
+        for election in proportional.iter().chain(logit.iter()) {
+            assert_eq!(election.total_votes(), 1000);
+            assert_eq!(
+                election.results.iter().map(|c| c.get_seats()).sum::<u16>(),
+                4
+            );
+        }
+    }
+
+    #[test]
+    fn test_multi_district_swing() {
+        let mut election = MultiDistrictElection::new()
+            .with_district("North", district([500, 450, 50]))
+            .with_district("South", district([500, 150, 350]));
+        election.compute().unwrap();
+
+        let target = BTreeMap::from([("B".to_string(), 0.5), ("A".to_string(), 0.3)]);
+        election.swing(SwingModel::Uniform, &target).unwrap();
+
+        let north = &election.district("North").unwrap().election;
+        assert_eq!(votes(north), vec![300, 650, 50]);
+        assert_eq!(north.results[1].get_seats(), 3);
+        assert_eq!(election.seats(), 8);
+    }
+}
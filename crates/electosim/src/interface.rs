@@ -1,3 +1,5 @@
+use crate::threshold::ThresholdOverride;
+
 /// Trait representing an entity that has votes.
 pub trait WithVotes {
     /// Returns the number of votes.
@@ -10,7 +12,7 @@ pub trait WithVotes {
     fn increase_votes(&mut self, n: i32) {
         let current_votes = self.get_votes();
         if n < 0 {
-            self.set_votes(current_votes.saturating_sub(n.abs() as u32));
+            self.set_votes(current_votes.saturating_sub(n.unsigned_abs()));
         } else {
             self.set_votes(current_votes + n as u32);
         }
@@ -29,13 +31,52 @@ pub trait WithSeats {
     fn increase_seats(&mut self, n: i16) {
         let current_seats = self.get_seats();
         if n < 0 {
-            self.set_seats(current_seats.saturating_sub(n.abs() as u16));
+            self.set_seats(current_seats.saturating_sub(n.unsigned_abs()));
         } else {
             self.set_seats(current_seats + n as u16);
         }
     }
 }
 
+/// A trait for objects that win constituencies, used by the alternative clauses of thresholds.
+///
+/// Types that do not track constituencies can use an empty `impl`: they win none.
+pub trait WithConstituencyWins {
+    /// Returns the number of constituencies won (0 by default).
+    fn get_constituency_wins(&self) -> u16 {
+        0
+    }
+
+    /// Sets the number of constituencies won (ignored by default).
+    fn set_constituency_wins(&mut self, _wins: u16) {}
+}
+
+/// A trait for candidacies that may be coalitions of several parties, used by graded thresholds.
+///
+/// Types that do not track coalitions can use an empty `impl`: they are single parties.
+pub trait WithCoalition {
+    /// Returns the number of parties of the candidacy (1 for a single party, the default).
+    fn get_coalition_parties(&self) -> u16 {
+        1
+    }
+
+    /// Sets the number of parties of the candidacy (ignored by default).
+    fn set_coalition_parties(&mut self, _parties: u16) {}
+}
+
+/// A trait for candidacies with their own threshold rule, replacing the general one.
+///
+/// Types that do not track overrides can use an empty `impl`: the general threshold applies to them.
+pub trait WithThresholdOverride {
+    /// Returns the threshold rule of the candidacy, if any (none by default).
+    fn get_threshold_override(&self) -> Option<ThresholdOverride> {
+        None
+    }
+
+    /// Sets the threshold rule of the candidacy (ignored by default).
+    fn set_threshold_override(&mut self, _rule: Option<ThresholdOverride>) {}
+}
+
 impl<T> WithVotes for Box<&mut T>
 where
     T: WithVotes,
@@ -62,9 +103,52 @@ where
     }
 }
 
+impl<T> WithConstituencyWins for Box<&mut T>
+where
+    T: WithConstituencyWins,
+{
+    fn get_constituency_wins(&self) -> u16 {
+        (**self).get_constituency_wins()
+    }
+
+    fn set_constituency_wins(&mut self, wins: u16) {
+        (**self).set_constituency_wins(wins);
+    }
+}
+
+impl<T> WithCoalition for Box<&mut T>
+where
+    T: WithCoalition,
+{
+    fn get_coalition_parties(&self) -> u16 {
+        (**self).get_coalition_parties()
+    }
+
+    fn set_coalition_parties(&mut self, parties: u16) {
+        (**self).set_coalition_parties(parties);
+    }
+}
+
+impl<T> WithThresholdOverride for Box<&mut T>
+where
+    T: WithThresholdOverride,
+{
+    fn get_threshold_override(&self) -> Option<ThresholdOverride> {
+        (**self).get_threshold_override()
+    }
+
+    fn set_threshold_override(&mut self, rule: Option<ThresholdOverride>) {
+        (**self).set_threshold_override(rule);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        methods::Method,
+        threshold::{compute_with_threshold, Threshold},
+    };
 
     struct Candidate {
         votes: u32,
@@ -127,6 +211,66 @@ mod tests {
         assert_eq!(candidate.get_seats(), 90);
     }
 
+    impl WithConstituencyWins for Candidate {}
+
+    impl WithCoalition for Candidate {}
+
+    impl WithThresholdOverride for Candidate {}
+
+    #[test]
+    fn test_default_constituency_wins() {
+        let mut candidate = Candidate {
+            votes: 1000,
+            seats: 0,
+        };
+
+        assert_eq!(candidate.get_constituency_wins(), 0);
+        candidate.set_constituency_wins(3);
+        assert_eq!(candidate.get_constituency_wins(), 0);
+    }
+
+    #[test]
+    fn test_default_coalition() {
+        let mut candidate = Candidate {
+            votes: 1000,
+            seats: 0,
+        };
+
+        assert_eq!(candidate.get_coalition_parties(), 1);
+        candidate.set_coalition_parties(3);
+        assert_eq!(candidate.get_coalition_parties(), 1);
+    }
+
+    #[test]
+    fn test_default_threshold_override() {
+        let mut candidate = Candidate {
+            votes: 1000,
+            seats: 0,
+        };
+
+        assert_eq!(candidate.get_threshold_override(), None);
+        candidate.set_threshold_override(Some(ThresholdOverride::Exempt));
+        assert_eq!(candidate.get_threshold_override(), None);
+
+        // The defaults are enough to use thresholds.
+        let mut candidates = vec![
+            candidate,
+            Candidate {
+                votes: 40,
+                seats: 0,
+            },
+        ];
+        compute_with_threshold(
+            &mut candidates,
+            2,
+            Method::DHONDT,
+            &Threshold::percentage(0.05),
+        )
+        .unwrap();
+        assert_eq!(candidates[0].get_seats(), 2);
+        assert_eq!(candidates[1].get_seats(), 0);
+    }
+
     #[test]
     fn test_with_box() {
         let mut candidate = Candidate {
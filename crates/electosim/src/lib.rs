@@ -13,6 +13,7 @@
 //! - Imperiali - Quotient
 //! - Droop
 //! - Winner Takes All
+//! - Balinski-Young (quota method)
 //!
 //! ## Usage
 //!
@@ -40,7 +41,7 @@
 //! The first statement in the `main` function creates a new [SimpleElection] with the candidates, the number of seats available, and the method to be used. The `compute` method is then called to compute the election results. Finally, the results are printed to the console.
 //!
 //! # `compute_` functions
-//! A method is a function with type `fn(&mut Vec<T>, u16) -> Result<(), &str>` where `T` is a type that implements the [`WithVotes`][interface::WithVotes] and [`WithSeats`][interface::WithSeats] traits.
+//! A method is a function with type `fn(&mut [T], u16) -> Result<(), &'static str>` where `T` is a type that implements the [`WithVotes`][interface::WithVotes] and [`WithSeats`][interface::WithSeats] traits.
 //! You can use the `compute_` functions directly if you want to compute the election results without using the [SimpleElection] struct. For example:
 //! ```rust
 //! use electosim::*;
@@ -60,21 +61,32 @@
 //! }
 //! ```
 //!
-//! There are some implementations of the `compute_` functions in the [methods::divisor] (ex: D'hondt) and [methods::remainder] (ex: Hare) modules.
+//! There are some implementations of the `compute_` functions in the [methods::divisor] (ex: D'hondt), [methods::remainder] (ex: Hare) and [methods::quota] (ex: Balinski-Young) modules.
+//!
+//! Methods working on ballots instead of candidacy votes live in their own modules, such as [methods::approval] (ex: Phragmén) and [methods::cardinal] (ex: STAR).
+//!
+//! # Thresholds
+//! The `cutoff` of a [SimpleElection] is a fraction of the votes that must be exceeded. Other thresholds (absolute, inclusive,
+//! over blank ballots or registered voters, national) are defined in the [threshold] module and set with [SimpleElection::with_threshold].
 
+pub mod analysis;
+pub mod apportionment;
+pub mod elections;
 pub mod interface;
+pub mod lists;
 pub mod macros;
 pub mod methods;
 pub mod metrics;
 pub mod models;
+pub mod threshold;
 pub mod utils;
 pub use interface::WithVotes;
-use methods::get_method_function;
 pub use methods::Method;
 pub use models::Candidacy;
-use utils::clear_results; // Add this line to import the SimpleElection struct
+use threshold::{compute_with_eligibility, Comparison, Threshold, ThresholdQualification};
 
 /// Represents a simple election.
+#[derive(Debug, Clone)]
 pub struct SimpleElection {
     /// The results of the election.
     pub results: Vec<Candidacy>,
@@ -84,6 +96,10 @@ pub struct SimpleElection {
     pub method: Method,
     /// Electoral cutoff
     pub cutoff: f32,
+    /// Electoral threshold. When set, it replaces `cutoff`.
+    pub threshold: Option<Threshold>,
+    /// Why each candidacy took part, or not, in the last computation.
+    pub qualifications: Vec<ThresholdQualification>,
 }
 
 impl SimpleElection {
@@ -94,9 +110,53 @@ impl SimpleElection {
             seats,
             method,
             cutoff: 0.0,
+            threshold: None,
+            qualifications: vec![],
         }
     }
 
+    /// Sets the threshold of the election, replacing `cutoff`.
+    ///
+    /// # Example (Spain: 3% including blank ballots)
+    ///
+    /// ```rust
+    /// use electosim::threshold::{Threshold, ThresholdBase};
+    /// use electosim::interface::WithSeats;
+    /// use electosim::*;
+    ///
+    /// let mut election = SimpleElection::new(vec![candidacy!(970), candidacy!(30)], 5, Method::DHONDT)
+    ///     .with_threshold(Threshold::percentage(0.03).with_base(ThresholdBase::ValidAndBlank { blank: 100 }));
+    ///
+    /// election.compute().unwrap();
+    /// assert_eq!(election.results[1].get_seats(), 0);
+    /// ```
+    pub fn with_threshold(mut self, threshold: Threshold) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the threshold applied by the election.
+    ///
+    /// Without a threshold, the candidacies must exceed `cutoff` as a fraction of the total votes.
+    pub fn effective_threshold(&self) -> Threshold {
+        match &self.threshold {
+            Some(threshold) => threshold.clone(),
+            None => Threshold::percentage(self.cutoff).with_comparison(Comparison::Exclusive),
+        }
+    }
+
+    /// Computes which candidacies take part in the distribution of seats, respecting their threshold overrides.
+    pub fn eligible(&self) -> Vec<bool> {
+        self.effective_threshold().eligible(&self.results)
+    }
+
+    /// Computes how many votes each candidacy is from winning or losing a seat.
+    ///
+    /// See [analysis::seat_margin].
+    pub fn margins(&self) -> Result<Vec<analysis::SeatMargin>, &'static str> {
+        analysis::seat_margins(self)
+    }
+
     pub fn total_votes(&self) -> u32 {
         self.results.iter().map(|c| c.get_votes()).sum()
     }
@@ -110,23 +170,52 @@ impl SimpleElection {
     /// # Returns
     ///
     /// Returns `Ok(())` if the computation is successful, otherwise returns an `Err` with an error message.
-    pub fn compute(&mut self) -> Result<(), &str> {
-        let fun = get_method_function(self.method);
-        let total_votes = self.total_votes() as f32;
-        let cutoff_votes = (total_votes * self.cutoff) as u32;
-        // compute_with_cutoff(fun, &mut self.results, self.seats, cutoff_votes)
-        clear_results(self.results.as_mut());
-
-        let mut filtered_results = self
-            .results
-            .iter_mut()
-            .filter(|c| c.get_votes() > cutoff_votes)
-            .map(|c| Box::new(c))
-            .collect::<Vec<_>>();
+    /// The qualification of each candidacy is recorded in `qualifications`.
+    ///
+    /// # Example (German minority in Poland, exempt from the 5% threshold)
+    ///
+    /// ```rust
+    /// use electosim::threshold::{ThresholdOverride, ThresholdQualification};
+    /// use electosim::interface::WithSeats;
+    /// use electosim::*;
+    ///
+    /// let mut election = election!(
+    ///     vec![
+    ///         candidacy!(9000),
+    ///         candidacy!(800).with_threshold_override(ThresholdOverride::Exempt),
+    ///         candidacy!(200),
+    ///     ],
+    ///     10,
+    ///     Method::SAINTELAGUE,
+    ///     0.05
+    /// );
+    ///
+    /// election.compute().unwrap();
+    /// assert_eq!(election.results[1].get_seats(), 1);
+    /// assert_eq!(election.qualifications[1], ThresholdQualification::Exempt);
+    /// assert_eq!(election.qualifications[2], ThresholdQualification::Excluded);
+    /// ```
+    pub fn compute(&mut self) -> Result<(), &'static str> {
+        let qualifications = self.effective_threshold().qualifications(&self.results);
+        self.compute_with_qualifications(qualifications)
+    }
 
-        fun(&mut filtered_results, self.seats).unwrap();
+    /// Computes the election results among the qualified candidacies, recording their qualifications.
+    pub(crate) fn compute_with_qualifications(
+        &mut self,
+        qualifications: Vec<ThresholdQualification>,
+    ) -> Result<(), &'static str> {
+        if self.results.is_empty() {
+            return Err("EMPTY_RESULTS");
+        }
 
-        Ok(())
+        let eligible = qualifications
+            .iter()
+            .map(|q| q.is_qualified())
+            .collect::<Vec<_>>();
+        self.qualifications = qualifications;
+
+        compute_with_eligibility(&mut self.results, self.seats, self.method, &eligible)
     }
 }
 
@@ -182,4 +271,25 @@ mod tests {
         assert_eq!(res.results[0].get_seats(), 13);
         assert_eq!(res.results[1].get_seats(), 0);
     }
+
+    #[test]
+    fn test_with_threshold() {
+        let mut res = election!(
+            vec![candidacy!(95, 0), candidacy!(5, 0),],
+            10,
+            Method::SAINTELAGUE,
+            0.05
+        );
+
+        // The cutoff must be exceeded, an inclusive threshold must be reached.
+        res.compute().unwrap();
+        assert_eq!(res.results[1].get_seats(), 0);
+
+        let mut res = res.with_threshold(Threshold::percentage(0.05));
+        res.compute().unwrap();
+        assert_eq!(res.results[1].get_seats(), 1);
+
+        let mut res = res.with_threshold(Threshold::absolute(100));
+        assert_eq!(res.compute(), Err("NO_ELIGIBLE_CANDIDACIES"));
+    }
 }
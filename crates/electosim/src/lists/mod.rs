@@ -0,0 +1,295 @@
+//! # Candidate lists
+//! Once a candidacy wins a number of seats, they are filled with candidates of its list.
+//! Who takes them depends on the ordering rule of the election:
+//! - Closed lists follow the order of presentation.
+//! - Open lists rank candidates by preference votes (ex: Finland, Brazil).
+//! - Flexible lists keep the order of presentation except for candidates reaching a personal vote threshold (ex: 5% in Sweden)
+//!   or redistribute the votes cast for the list as a whole (ex: list-vote pooling in Belgium).
+//!
+//! # Example
+//!
+//! ```rust
+//! use electosim::lists::{CandidateList, ListCandidate, OrderingRule};
+//!
+//! let list = CandidateList::new(
+//!     vec![
+//!         ListCandidate::new("Virtanen", 1200),
+//!         ListCandidate::new("Korhonen", 3400),
+//!         ListCandidate::new("Mäkinen", 800),
+//!     ],
+//!     0,
+//! );
+//!
+//! let elected = list.elect(5400, 2, OrderingRule::Preference).unwrap();
+//! assert_eq!(elected, vec!["Korhonen", "Virtanen"]);
+//! ```
+
+use crate::interface::{WithSeats, WithVotes};
+use std::cmp::Reverse;
+
+/// Represents a candidate of a list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListCandidate {
+    /// The name of the candidate.
+    pub name: String,
+    /// The preference (personal) votes received by the candidate.
+    pub preference_votes: u32,
+}
+
+impl ListCandidate {
+    /// Creates a new `ListCandidate`.
+    pub fn new(name: impl Into<String>, preference_votes: u32) -> Self {
+        ListCandidate {
+            name: name.into(),
+            preference_votes,
+        }
+    }
+}
+
+/// The rule ordering the candidates of a list.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OrderingRule {
+    /// Order of presentation.
+    Closed,
+    /// Preference votes, ties broken by order of presentation.
+    Preference,
+    /// Candidates with at least this share of the party votes go first, ordered by preference votes.
+    /// The rest follow the order of presentation (ex: 0.05 in Sweden).
+    PersonalThreshold(f32),
+    /// Belgian list-vote pooling, where the given fraction of the list votes is devolved (ex: 0.5).
+    ///
+    /// The eligibility figure is `party_votes * seats / (seats + 1)`. Following the order of presentation,
+    /// the pool tops up candidates below the figure until it is exhausted. Candidates reaching the figure are elected first,
+    /// remaining seats go to the candidates with the most preference and devolved votes.
+    ListVotePooling(f32),
+}
+
+/// Represents the list of candidates of a candidacy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateList {
+    /// The candidates in order of presentation.
+    pub candidates: Vec<ListCandidate>,
+    /// Votes cast for the list as a whole, without preference for any candidate.
+    pub list_votes: u32,
+}
+
+impl CandidateList {
+    /// Creates a new `CandidateList`.
+    ///
+    /// # Arguments
+    ///
+    /// * `candidates` - The candidates in order of presentation.
+    /// * `list_votes` - Votes cast for the list as a whole.
+    pub fn new(candidates: Vec<ListCandidate>, list_votes: u32) -> Self {
+        CandidateList {
+            candidates,
+            list_votes,
+        }
+    }
+
+    /// Ranks the candidates of the list, from first to last elected.
+    ///
+    /// # Arguments
+    ///
+    /// * `party_votes` - The votes received by the candidacy.
+    /// * `seats` - The seats won by the candidacy.
+    /// * `rule` - The ordering rule.
+    ///
+    /// # Returns
+    ///
+    /// The indexes of the candidates in order of election.
+    pub fn rank(&self, party_votes: u32, seats: u16, rule: OrderingRule) -> Vec<usize> {
+        let mut ranking: Vec<usize> = (0..self.candidates.len()).collect();
+        let preference = |idx: usize| self.candidates[idx].preference_votes as u64;
+
+        match rule {
+            OrderingRule::Closed => {}
+            OrderingRule::Preference => {
+                ranking.sort_by_key(|&idx| Reverse(preference(idx)));
+            }
+            OrderingRule::PersonalThreshold(share) => {
+                let min_votes = share as f64 * party_votes as f64;
+                let (mut personal, rest): (Vec<usize>, Vec<usize>) = ranking
+                    .into_iter()
+                    .partition(|&idx| preference(idx) as f64 >= min_votes);
+
+                personal.sort_by_key(|&idx| Reverse(preference(idx)));
+                ranking = personal.into_iter().chain(rest).collect();
+            }
+            OrderingRule::ListVotePooling(devolution) => {
+                // A candidate is eligible when votes * (seats + 1) >= party_votes * seats.
+                let seats = seats as u64;
+                let target = (party_votes as u64 * seats).div_ceil(seats + 1);
+                let mut pool = (self.list_votes as f64 * devolution as f64).floor() as u64;
+
+                let totals = ranking
+                    .iter()
+                    .map(|&idx| {
+                        let votes = preference(idx);
+                        let devolved = target.saturating_sub(votes).min(pool);
+                        pool -= devolved;
+                        votes + devolved
+                    })
+                    .collect::<Vec<_>>();
+
+                let (mut eligible, mut rest): (Vec<usize>, Vec<usize>) =
+                    ranking.into_iter().partition(|&idx| totals[idx] >= target);
+
+                eligible.sort_by_key(|&idx| Reverse(totals[idx]));
+                rest.sort_by_key(|&idx| Reverse(totals[idx]));
+                ranking = eligible.into_iter().chain(rest).collect();
+            }
+        }
+
+        ranking
+    }
+
+    /// Returns the names of the candidates filling the seats of the candidacy.
+    ///
+    /// # Arguments
+    ///
+    /// * `party_votes` - The votes received by the candidacy.
+    /// * `seats` - The seats won by the candidacy.
+    /// * `rule` - The ordering rule.
+    ///
+    /// # Returns
+    ///
+    /// The elected names in order of election, or `Err("NOT_ENOUGH_CANDIDATES")` if the list is shorter than `seats`.
+    pub fn elect(
+        &self,
+        party_votes: u32,
+        seats: u16,
+        rule: OrderingRule,
+    ) -> Result<Vec<String>, &'static str> {
+        if seats as usize > self.candidates.len() {
+            return Err("NOT_ENOUGH_CANDIDATES");
+        }
+
+        Ok(self
+            .rank(party_votes, seats, rule)
+            .into_iter()
+            .take(seats as usize)
+            .map(|idx| self.candidates[idx].name.clone())
+            .collect())
+    }
+}
+
+/// Maps the seats won by each candidacy to the names of its elected candidates.
+///
+/// # Arguments
+///
+/// * `results` - The candidacies, with their votes and seats already computed.
+/// * `lists` - The list of candidates of each candidacy, in the same order as `results`.
+/// * `rule` - The ordering rule.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::lists::{elect_candidates, CandidateList, ListCandidate, OrderingRule};
+/// use electosim::methods::divisor::compute_dhondt;
+/// use electosim::*;
+///
+/// let mut candidacies = vec![candidacy!(600), candidacy!(400)];
+/// let lists = vec![
+///     CandidateList::new(vec![ListCandidate::new("A1", 0), ListCandidate::new("A2", 0)], 0),
+///     CandidateList::new(vec![ListCandidate::new("B1", 0), ListCandidate::new("B2", 0)], 0),
+/// ];
+///
+/// compute_dhondt(&mut candidacies, 3).unwrap();
+/// let elected = elect_candidates(&candidacies, &lists, OrderingRule::Closed).unwrap();
+/// assert_eq!(elected, vec![vec!["A1", "A2"], vec!["B1"]]);
+/// ```
+pub fn elect_candidates<T>(
+    results: &[T],
+    lists: &[CandidateList],
+    rule: OrderingRule,
+) -> Result<Vec<Vec<String>>, &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    if results.len() != lists.len() {
+        return Err("LISTS_MISMATCH");
+    }
+
+    results
+        .iter()
+        .zip(lists)
+        .map(|(c, list)| list.elect(c.get_votes(), c.get_seats(), rule))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    fn list() -> CandidateList {
+        CandidateList::new(
+            vec![
+                ListCandidate::new("A", 300),
+                ListCandidate::new("B", 50),
+                ListCandidate::new("C", 700),
+                ListCandidate::new("D", 400),
+            ],
+            0,
+        )
+    }
+
+    #[test]
+    fn test_ordering_rules() {
+        let list = list();
+
+        assert_eq!(
+            list.elect(10000, 2, OrderingRule::Closed).unwrap(),
+            vec!["A", "B"]
+        );
+        assert_eq!(
+            list.elect(10000, 2, OrderingRule::Preference).unwrap(),
+            vec!["C", "D"]
+        );
+        // Only C reaches 5% of 10000 votes.
+        assert_eq!(
+            list.elect(10000, 3, OrderingRule::PersonalThreshold(0.05))
+                .unwrap(),
+            vec!["C", "A", "B"]
+        );
+    }
+
+    #[test]
+    fn test_list_vote_pooling() {
+        let mut list = list();
+        list.list_votes = 1000;
+
+        // Eligibility figure: 3000 * 2 / 3 = 2000. The pool (500) tops up A to 800 and is exhausted.
+        assert_eq!(
+            list.rank(3000, 2, OrderingRule::ListVotePooling(0.5)),
+            vec![0, 2, 3, 1]
+        );
+
+        // Eligibility figure: 900 * 2 / 3 = 600. The pool tops up A (300) and gives the rest to B (200).
+        assert_eq!(
+            list.rank(900, 2, OrderingRule::ListVotePooling(0.5)),
+            vec![2, 0, 3, 1]
+        );
+        assert_eq!(
+            list.elect(900, 2, OrderingRule::ListVotePooling(0.5))
+                .unwrap(),
+            vec!["C", "A"]
+        );
+    }
+
+    #[test]
+    fn test_elect_candidates() {
+        let candidacies = vec![Candidacy::new(10000, 2), Candidacy::new(500, 5)];
+        let lists = vec![list(), list()];
+
+        assert_eq!(
+            elect_candidates(&candidacies, &lists[..1], OrderingRule::Closed),
+            Err("LISTS_MISMATCH")
+        );
+        assert_eq!(
+            elect_candidates(&candidacies, &lists, OrderingRule::Closed),
+            Err("NOT_ENOUGH_CANDIDATES")
+        );
+    }
+}
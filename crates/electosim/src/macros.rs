@@ -26,16 +26,20 @@ macro_rules! election {
         $crate::SimpleElection {
             results: $results,
             seats: 0,
-            method: electosim::Method::DHONDT,
+            method: $crate::Method::DHONDT,
             cutoff: 0.0,
+            threshold: None,
+            qualifications: vec![],
         }
     };
     ($results:expr, $seats:expr) => {
         $crate::SimpleElection {
             results: $results,
             seats: $seats,
-            method: electosim::Method::DHONDT,
+            method: $crate::Method::DHONDT,
             cutoff: 0.0,
+            threshold: None,
+            qualifications: vec![],
         }
     };
     ($results:expr, $seats:expr, $method:expr) => {
@@ -44,6 +48,8 @@ macro_rules! election {
             seats: $seats,
             method: $method,
             cutoff: 0.0,
+            threshold: None,
+            qualifications: vec![],
         }
     };
     ($results:expr, $seats:expr, $method:expr, $coff:expr) => {
@@ -52,6 +58,8 @@ macro_rules! election {
             seats: $seats,
             method: $method,
             cutoff: $coff,
+            threshold: None,
+            qualifications: vec![],
         }
     };
 }
@@ -0,0 +1,461 @@
+//! # Approval-based multiwinner methods
+//! Each voter approves a subset of the candidates and a committee of `seats` candidates is elected.
+//! Unlike the list methods in [divisor][crate::methods::divisor] and [remainder][crate::methods::remainder],
+//! proportionality is achieved between groups of voters with similar approval sets instead of between parties.
+//!
+//! The following methods are available:
+//! - Proportional Approval Voting ([compute_pav])
+//! - Sequential Proportional Approval Voting ([compute_seq_pav])
+//! - Sequential Phragmén ([compute_seq_phragmen])
+//! - Method of Equal Shares ([compute_equal_shares])
+//!
+//! Ties are always broken in favour of the candidate with the lowest index.
+
+/// Represents a group of voters casting the same approval ballot.
+#[derive(Debug, Clone)]
+pub struct ApprovalBallot {
+    /// Indexes of the approved candidates.
+    pub approvals: Vec<usize>,
+    /// Number of voters casting this ballot.
+    pub weight: u32,
+}
+
+impl ApprovalBallot {
+    /// Creates a new `ApprovalBallot`.
+    ///
+    /// # Arguments
+    ///
+    /// * `approvals` - Indexes of the approved candidates.
+    /// * `weight` - Number of voters casting this ballot.
+    pub fn new(approvals: Vec<usize>, weight: u32) -> Self {
+        ApprovalBallot { approvals, weight }
+    }
+
+    fn approves(&self, candidate: usize) -> bool {
+        self.approvals.contains(&candidate)
+    }
+}
+
+/// The outcome of an approval-based multiwinner method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApprovalResult {
+    /// Indexes of the elected candidates, in the order they were elected.
+    pub committee: Vec<usize>,
+    /// Number of elected candidates approved by each ballot.
+    pub satisfaction: Vec<u16>,
+    /// Load (Phragmén) or budget spent (Equal Shares) by each voter of each ballot.
+    /// `None` for methods without a notion of load.
+    pub loads: Option<Vec<f32>>,
+}
+
+fn validate(ballots: &[ApprovalBallot], candidates: usize, seats: u16) -> Result<(), &'static str> {
+    if candidates == 0 {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if seats as usize > candidates {
+        return Err("NOT_ENOUGH_CANDIDATES");
+    }
+
+    if ballots
+        .iter()
+        .any(|b| b.approvals.iter().any(|&c| c >= candidates))
+    {
+        return Err("INVALID_BALLOT");
+    }
+
+    Ok(())
+}
+
+fn satisfaction(ballots: &[ApprovalBallot], committee: &[usize]) -> Vec<u16> {
+    ballots
+        .iter()
+        .map(|b| committee.iter().filter(|&&c| b.approves(c)).count() as u16)
+        .collect()
+}
+
+/// Returns the index of the maximum score, keeping the lowest index on ties.
+fn best_candidate(scores: impl Iterator<Item = (usize, f64)>) -> Option<usize> {
+    let mut best: Option<(usize, f64)> = None;
+
+    for (idx, score) in scores {
+        match best {
+            Some((_, b)) if score <= b + 1e-9 => {}
+            _ => best = Some((idx, score)),
+        }
+    }
+
+    best.map(|(idx, _)| idx)
+}
+
+fn pav_score(ballots: &[ApprovalBallot], committee: &[usize]) -> f64 {
+    satisfaction(ballots, committee)
+        .iter()
+        .zip(ballots)
+        .map(|(&s, b)| b.weight as f64 * (1..=s).map(|i| 1.0 / i as f64).sum::<f64>())
+        .sum()
+}
+
+/// Proportional Approval Voting (PAV).
+///
+/// Elects the committee maximising the sum over voters of `1 + 1/2 + ... + 1/s`, where `s` is the number of elected candidates approved by the voter.
+/// The optimal committee is found by exhaustive search, so the running time grows combinatorially with the number of candidates.
+///
+/// # Arguments
+///
+/// * `ballots` - The approval ballots.
+/// * `candidates` - The number of candidates.
+/// * `seats` - The size of the committee.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::methods::approval::{compute_pav, ApprovalBallot};
+///
+/// let ballots = vec![
+///     ApprovalBallot::new(vec![0, 1], 6),
+///     ApprovalBallot::new(vec![2], 4),
+/// ];
+///
+/// let result = compute_pav(&ballots, 3, 2).unwrap();
+/// assert_eq!(result.committee, vec![0, 2]);
+/// ```
+pub fn compute_pav(
+    ballots: &[ApprovalBallot],
+    candidates: usize,
+    seats: u16,
+) -> Result<ApprovalResult, &'static str> {
+    validate(ballots, candidates, seats)?;
+
+    let seats = seats as usize;
+    let mut combination: Vec<usize> = (0..seats).collect();
+    let mut best = combination.clone();
+    let mut best_score = pav_score(ballots, &combination);
+
+    // Iterate over all combinations in lexicographic order.
+    loop {
+        let pivot = (0..seats)
+            .rev()
+            .find(|&i| combination[i] < candidates - seats + i);
+
+        match pivot {
+            Some(i) => {
+                combination[i] += 1;
+                for j in i + 1..seats {
+                    combination[j] = combination[j - 1] + 1;
+                }
+            }
+            None => break,
+        }
+
+        let score = pav_score(ballots, &combination);
+        if score > best_score + 1e-9 {
+            best_score = score;
+            best = combination.clone();
+        }
+    }
+
+    Ok(ApprovalResult {
+        satisfaction: satisfaction(ballots, &best),
+        committee: best,
+        loads: None,
+    })
+}
+
+/// Sequential Proportional Approval Voting.
+///
+/// Candidates are elected one by one, each time choosing the candidate with the largest marginal PAV score:
+/// every voter approving the candidate contributes `1 / (s + 1)`, where `s` is the number of elected candidates already approved by the voter.
+///
+/// # Arguments
+///
+/// * `ballots` - The approval ballots.
+/// * `candidates` - The number of candidates.
+/// * `seats` - The size of the committee.
+pub fn compute_seq_pav(
+    ballots: &[ApprovalBallot],
+    candidates: usize,
+    seats: u16,
+) -> Result<ApprovalResult, &'static str> {
+    validate(ballots, candidates, seats)?;
+
+    let mut committee: Vec<usize> = Vec::with_capacity(seats as usize);
+    let mut sat = vec![0u16; ballots.len()];
+
+    for _ in 0..seats {
+        let scores = (0..candidates).filter(|c| !committee.contains(c)).map(|c| {
+            let score = ballots
+                .iter()
+                .zip(&sat)
+                .filter(|(b, _)| b.approves(c))
+                .map(|(b, &s)| b.weight as f64 / (s + 1) as f64)
+                .sum::<f64>();
+
+            (c, score)
+        });
+
+        let elected = best_candidate(scores).ok_or("EMPTY_RESULTS")?;
+        ballots.iter().zip(sat.iter_mut()).for_each(|(b, s)| {
+            if b.approves(elected) {
+                *s += 1;
+            }
+        });
+        committee.push(elected);
+    }
+
+    Ok(ApprovalResult {
+        committee,
+        satisfaction: sat,
+        loads: None,
+    })
+}
+
+/// Sequential Phragmén.
+///
+/// Each elected candidate puts a load of 1 that is shared by the voters approving it.
+/// Candidates are elected one by one, each time choosing the candidate that minimises the maximum load among its approvers.
+/// The loads carried by the voters of each ballot are reported in [ApprovalResult::loads].
+///
+/// # Arguments
+///
+/// * `ballots` - The approval ballots.
+/// * `candidates` - The number of candidates.
+/// * `seats` - The size of the committee.
+pub fn compute_seq_phragmen(
+    ballots: &[ApprovalBallot],
+    candidates: usize,
+    seats: u16,
+) -> Result<ApprovalResult, &'static str> {
+    validate(ballots, candidates, seats)?;
+
+    let mut committee: Vec<usize> = Vec::with_capacity(seats as usize);
+    let mut loads = vec![0f64; ballots.len()];
+
+    for _ in 0..seats {
+        let new_loads = (0..candidates).filter(|c| !committee.contains(c)).map(|c| {
+            let (support, load) = ballots
+                .iter()
+                .zip(&loads)
+                .filter(|(b, _)| b.approves(c))
+                .fold((0f64, 0f64), |(w, l), (b, &load)| {
+                    (w + b.weight as f64, l + b.weight as f64 * load)
+                });
+
+            match support > 0.0 {
+                true => (c, -(1.0 + load) / support),
+                false => (c, f64::NEG_INFINITY),
+            }
+        });
+
+        let elected = best_candidate(new_loads).ok_or("EMPTY_RESULTS")?;
+        let (support, load) = ballots
+            .iter()
+            .zip(&loads)
+            .filter(|(b, _)| b.approves(elected))
+            .fold((0f64, 0f64), |(w, l), (b, &load)| {
+                (w + b.weight as f64, l + b.weight as f64 * load)
+            });
+
+        if support > 0.0 {
+            let new_load = (1.0 + load) / support;
+            ballots.iter().zip(loads.iter_mut()).for_each(|(b, l)| {
+                if b.approves(elected) {
+                    *l = new_load;
+                }
+            });
+        }
+        committee.push(elected);
+    }
+
+    Ok(ApprovalResult {
+        satisfaction: satisfaction(ballots, &committee),
+        committee,
+        loads: Some(loads.iter().map(|&l| l as f32).collect()),
+    })
+}
+
+/// Returns the minimum per-voter payment needed to buy a candidate of cost 1, if affordable.
+fn equal_shares_price(supporters: &mut [(f64, f64)]) -> Option<f64> {
+    let budget: f64 = supporters.iter().map(|(w, b)| w * b).sum();
+    if budget < 1.0 - 1e-9 {
+        return None;
+    }
+
+    supporters.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    let mut remaining = 1.0;
+    let mut weight: f64 = supporters.iter().map(|(w, _)| w).sum();
+
+    for &(w, b) in supporters.iter() {
+        let rho = remaining / weight;
+        if b >= rho {
+            return Some(rho);
+        }
+        remaining -= w * b;
+        weight -= w;
+    }
+
+    None
+}
+
+/// Method of Equal Shares (MES).
+///
+/// Every voter starts with an equal share of a budget of `seats` and each candidate costs 1.
+/// Candidates are bought one by one, each time choosing the candidate whose approvers can pay for it with the smallest maximum individual payment.
+/// When no remaining candidate is affordable, the remaining seats are filled by approval count (utilitarian completion).
+/// The budget spent by the voters of each ballot is reported in [ApprovalResult::loads].
+///
+/// # Arguments
+///
+/// * `ballots` - The approval ballots.
+/// * `candidates` - The number of candidates.
+/// * `seats` - The size of the committee.
+pub fn compute_equal_shares(
+    ballots: &[ApprovalBallot],
+    candidates: usize,
+    seats: u16,
+) -> Result<ApprovalResult, &'static str> {
+    validate(ballots, candidates, seats)?;
+
+    let voters: f64 = ballots.iter().map(|b| b.weight as f64).sum();
+    let share = match voters > 0.0 {
+        true => seats as f64 / voters,
+        false => 0.0,
+    };
+    let mut budgets = vec![share; ballots.len()];
+    let mut committee: Vec<usize> = Vec::with_capacity(seats as usize);
+
+    while committee.len() < seats as usize {
+        let prices = (0..candidates)
+            .filter(|c| !committee.contains(c))
+            .filter_map(|c| {
+                let mut supporters = ballots
+                    .iter()
+                    .zip(&budgets)
+                    .filter(|(b, _)| b.approves(c))
+                    .map(|(b, &budget)| (b.weight as f64, budget))
+                    .collect::<Vec<_>>();
+
+                equal_shares_price(&mut supporters).map(|rho| (c, rho))
+            })
+            .collect::<Vec<_>>();
+
+        let (elected, rho) = match best_candidate(prices.iter().map(|&(c, rho)| (c, -rho))) {
+            Some(c) => prices.iter().find(|(p, _)| *p == c).copied().unwrap(),
+            None => break,
+        };
+
+        ballots
+            .iter()
+            .zip(budgets.iter_mut())
+            .for_each(|(b, budget)| {
+                if b.approves(elected) {
+                    *budget -= budget.min(rho);
+                }
+            });
+        committee.push(elected);
+    }
+
+    while committee.len() < seats as usize {
+        let scores = (0..candidates).filter(|c| !committee.contains(c)).map(|c| {
+            let approvals = ballots
+                .iter()
+                .filter(|b| b.approves(c))
+                .map(|b| b.weight as f64)
+                .sum::<f64>();
+
+            (c, approvals)
+        });
+
+        committee.push(best_candidate(scores).ok_or("EMPTY_RESULTS")?);
+    }
+
+    Ok(ApprovalResult {
+        satisfaction: satisfaction(ballots, &committee),
+        committee,
+        loads: Some(budgets.iter().map(|&b| (share - b) as f32).collect()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two cohesive groups: 2/3 of the voters approve {0, 1, 2}, 1/3 approve {3, 4}.
+    fn two_groups() -> Vec<ApprovalBallot> {
+        vec![
+            ApprovalBallot::new(vec![0, 1, 2], 20),
+            ApprovalBallot::new(vec![3, 4], 10),
+        ]
+    }
+
+    #[test]
+    fn test_proportional_committees() {
+        let ballots = two_groups();
+
+        for f in [
+            compute_pav,
+            compute_seq_pav,
+            compute_seq_phragmen,
+            compute_equal_shares,
+        ] {
+            let mut result = f(&ballots, 5, 3).unwrap();
+            result.committee.sort();
+            assert_eq!(result.committee, vec![0, 1, 3]);
+            assert_eq!(result.satisfaction, vec![2, 1]);
+        }
+    }
+
+    #[test]
+    fn test_seq_phragmen_loads() {
+        let result = compute_seq_phragmen(&two_groups(), 5, 3).unwrap();
+
+        assert_eq!(result.committee, vec![0, 1, 3]);
+        let loads = result.loads.unwrap();
+        assert!((loads[0] - 0.1).abs() < 1e-6);
+        assert!((loads[1] - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_pav_differs_from_seq_pav() {
+        // Classic example where sequential PAV is not optimal.
+        let ballots = vec![
+            ApprovalBallot::new(vec![0, 1], 3),
+            ApprovalBallot::new(vec![0, 2], 3),
+            ApprovalBallot::new(vec![1], 2),
+            ApprovalBallot::new(vec![2], 2),
+        ];
+
+        assert_eq!(
+            compute_seq_pav(&ballots, 3, 2).unwrap().committee,
+            vec![0, 1]
+        );
+        assert_eq!(compute_pav(&ballots, 3, 2).unwrap().committee, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_equal_shares_completion() {
+        // Only candidate 0 is affordable, candidate 1 is elected by utilitarian completion.
+        let ballots = vec![
+            ApprovalBallot::new(vec![0], 2),
+            ApprovalBallot::new(vec![1], 1),
+            ApprovalBallot::new(vec![2], 1),
+        ];
+
+        let result = compute_equal_shares(&ballots, 3, 2).unwrap();
+        assert_eq!(result.committee, vec![0, 1]);
+        assert_eq!(result.loads, Some(vec![0.5, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let ballots = vec![ApprovalBallot::new(vec![3], 1)];
+
+        assert_eq!(compute_pav(&ballots, 0, 1), Err("EMPTY_RESULTS"));
+        assert_eq!(
+            compute_seq_pav(&ballots, 2, 3),
+            Err("NOT_ENOUGH_CANDIDATES")
+        );
+        assert_eq!(compute_seq_phragmen(&ballots, 3, 1), Err("INVALID_BALLOT"));
+    }
+}
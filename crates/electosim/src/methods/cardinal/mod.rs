@@ -0,0 +1,387 @@
+//! # Cardinal methods
+//! Each voter grades every candidate on a numeric scale (higher is better) and a single winner is elected.
+//!
+//! The following methods are available:
+//! - Score voting ([compute_score])
+//! - STAR: Score Then Automatic Runoff ([compute_star])
+//! - Majority Judgment ([compute_majority_judgment])
+//!
+//! Ties not resolved by the method itself are broken in favour of the candidate with the lowest index.
+
+use std::cmp::Ordering;
+
+/// Represents a group of voters casting the same score ballot.
+#[derive(Debug, Clone)]
+pub struct ScoreBallot {
+    /// Score given to each candidate, indexed by candidate.
+    pub scores: Vec<u8>,
+    /// Number of voters casting this ballot.
+    pub weight: u32,
+}
+
+impl ScoreBallot {
+    /// Creates a new `ScoreBallot`.
+    ///
+    /// # Arguments
+    ///
+    /// * `scores` - Score given to each candidate, indexed by candidate.
+    /// * `weight` - Number of voters casting this ballot.
+    pub fn new(scores: Vec<u8>, weight: u32) -> Self {
+        ScoreBallot { scores, weight }
+    }
+}
+
+/// How the scores of each candidate are aggregated in score voting.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ScoreAggregation {
+    /// Total score.
+    Sum,
+    /// Total score divided by the number of voters.
+    Average,
+}
+
+/// The outcome of score voting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreResult {
+    /// Index of the winner.
+    pub winner: usize,
+    /// Aggregated score of each candidate.
+    pub scores: Vec<f32>,
+}
+
+/// The outcome of a STAR election.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StarResult {
+    /// Index of the winner.
+    pub winner: usize,
+    /// Total score of each candidate in the scoring round.
+    pub scores: Vec<f32>,
+    /// The two highest scoring candidates, in scoring order.
+    pub finalists: [usize; 2],
+    /// Number of voters preferring each finalist in the runoff.
+    pub preferences: [u32; 2],
+    /// Number of voters giving the same score to both finalists.
+    pub no_preference: u32,
+}
+
+/// The outcome of a Majority Judgment election.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MajorityJudgmentResult {
+    /// Index of the winner.
+    pub winner: usize,
+    /// Median grade (lower median) of each candidate.
+    pub median_grades: Vec<u8>,
+    /// Candidates ordered from best to worst.
+    pub ranking: Vec<usize>,
+}
+
+fn validate(ballots: &[ScoreBallot], candidates: usize) -> Result<(), &'static str> {
+    if candidates == 0 {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if ballots.iter().any(|b| b.scores.len() != candidates) {
+        return Err("INVALID_BALLOT");
+    }
+
+    Ok(())
+}
+
+fn total_scores(ballots: &[ScoreBallot], candidates: usize) -> Vec<f32> {
+    (0..candidates)
+        .map(|c| {
+            ballots
+                .iter()
+                .map(|b| b.scores[c] as f64 * b.weight as f64)
+                .sum::<f64>() as f32
+        })
+        .collect()
+}
+
+/// Returns the index of the maximum score, keeping the lowest index on ties.
+fn best_candidate(scores: &[f32], skip: Option<usize>) -> Option<usize> {
+    scores
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| Some(*idx) != skip)
+        .fold(
+            None,
+            |best: Option<(usize, f32)>, (idx, &score)| match best {
+                Some((_, b)) if score <= b => best,
+                _ => Some((idx, score)),
+            },
+        )
+        .map(|(idx, _)| idx)
+}
+
+/// Score voting.
+///
+/// The candidate with the highest aggregated score wins.
+///
+/// # Arguments
+///
+/// * `ballots` - The score ballots.
+/// * `candidates` - The number of candidates.
+/// * `aggregation` - Whether scores are summed or averaged.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::methods::cardinal::{compute_score, ScoreAggregation, ScoreBallot};
+///
+/// let ballots = vec![
+///     ScoreBallot::new(vec![5, 0, 3], 4),
+///     ScoreBallot::new(vec![0, 5, 4], 3),
+/// ];
+///
+/// let result = compute_score(&ballots, 3, ScoreAggregation::Sum).unwrap();
+/// assert_eq!(result.winner, 2);
+/// assert_eq!(result.scores, vec![20.0, 15.0, 24.0]);
+/// ```
+pub fn compute_score(
+    ballots: &[ScoreBallot],
+    candidates: usize,
+    aggregation: ScoreAggregation,
+) -> Result<ScoreResult, &'static str> {
+    validate(ballots, candidates)?;
+
+    let mut scores = total_scores(ballots, candidates);
+    if aggregation == ScoreAggregation::Average {
+        let voters: u32 = ballots.iter().map(|b| b.weight).sum();
+        if voters > 0 {
+            scores.iter_mut().for_each(|s| *s /= voters as f32);
+        }
+    }
+
+    Ok(ScoreResult {
+        winner: best_candidate(&scores, None).ok_or("EMPTY_RESULTS")?,
+        scores,
+    })
+}
+
+/// STAR voting (Score Then Automatic Runoff).
+///
+/// The two candidates with the highest total score advance to an automatic runoff,
+/// won by the finalist preferred (scored higher) by more voters.
+/// Ties in the runoff are broken in favour of the finalist with the highest score.
+///
+/// # Arguments
+///
+/// * `ballots` - The score ballots.
+/// * `candidates` - The number of candidates (at least 2).
+pub fn compute_star(
+    ballots: &[ScoreBallot],
+    candidates: usize,
+) -> Result<StarResult, &'static str> {
+    validate(ballots, candidates)?;
+    if candidates < 2 {
+        return Err("NOT_ENOUGH_CANDIDATES");
+    }
+
+    let scores = total_scores(ballots, candidates);
+    let first = best_candidate(&scores, None).ok_or("EMPTY_RESULTS")?;
+    let second = best_candidate(&scores, Some(first)).ok_or("EMPTY_RESULTS")?;
+
+    let mut preferences = [0u32; 2];
+    let mut no_preference = 0u32;
+    ballots
+        .iter()
+        .for_each(|b| match b.scores[first].cmp(&b.scores[second]) {
+            Ordering::Greater => preferences[0] += b.weight,
+            Ordering::Less => preferences[1] += b.weight,
+            Ordering::Equal => no_preference += b.weight,
+        });
+
+    let winner = match preferences[1] > preferences[0] {
+        true => second,
+        false => first,
+    };
+
+    Ok(StarResult {
+        winner,
+        scores,
+        finalists: [first, second],
+        preferences,
+        no_preference,
+    })
+}
+
+/// Number of voters giving each grade to a candidate, indexed by grade.
+fn grade_distribution(ballots: &[ScoreBallot], candidate: usize) -> Vec<u64> {
+    let max_grade = ballots
+        .iter()
+        .map(|b| b.scores[candidate])
+        .max()
+        .unwrap_or(0);
+    let mut counts = vec![0u64; max_grade as usize + 1];
+
+    ballots
+        .iter()
+        .for_each(|b| counts[b.scores[candidate] as usize] += b.weight as u64);
+
+    counts
+}
+
+/// Lower median of a grade distribution.
+fn median_grade(counts: &[u64]) -> Option<u8> {
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+
+    let position = (total - 1) / 2;
+    let mut cumulative = 0;
+    counts.iter().enumerate().find_map(|(grade, &n)| {
+        cumulative += n;
+        (cumulative > position).then_some(grade as u8)
+    })
+}
+
+/// Compares the majority values of two candidates: medians are compared and,
+/// while equal, one median grade is removed from each distribution.
+fn compare_majority_values(a: &[u64], b: &[u64]) -> Ordering {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+
+    loop {
+        match (median_grade(&a), median_grade(&b)) {
+            (Some(ma), Some(mb)) if ma == mb => {
+                a[ma as usize] -= 1;
+                b[mb as usize] -= 1;
+            }
+            (Some(ma), Some(mb)) => return ma.cmp(&mb),
+            (a, b) => return a.cmp(&b),
+        }
+    }
+}
+
+/// Majority Judgment.
+///
+/// Candidates are ranked by their median grade. Ties are broken by repeatedly removing one median grade
+/// from each tied candidate and comparing the new medians (the majority value).
+///
+/// # Arguments
+///
+/// * `ballots` - The ballots, where each score is a grade (higher is better).
+/// * `candidates` - The number of candidates.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::methods::cardinal::{compute_majority_judgment, ScoreBallot};
+///
+/// let ballots = vec![
+///     ScoreBallot::new(vec![4, 3], 2),
+///     ScoreBallot::new(vec![1, 3], 1),
+///     ScoreBallot::new(vec![3, 2], 2),
+/// ];
+///
+/// let result = compute_majority_judgment(&ballots, 2).unwrap();
+/// assert_eq!(result.median_grades, vec![3, 3]);
+/// assert_eq!(result.winner, 0);
+/// ```
+pub fn compute_majority_judgment(
+    ballots: &[ScoreBallot],
+    candidates: usize,
+) -> Result<MajorityJudgmentResult, &'static str> {
+    validate(ballots, candidates)?;
+
+    let distributions = (0..candidates)
+        .map(|c| grade_distribution(ballots, c))
+        .collect::<Vec<_>>();
+
+    let median_grades = distributions
+        .iter()
+        .map(|d| median_grade(d).unwrap_or(0))
+        .collect();
+
+    let mut ranking: Vec<usize> = (0..candidates).collect();
+    ranking.sort_by(|&a, &b| compare_majority_values(&distributions[b], &distributions[a]));
+
+    Ok(MajorityJudgmentResult {
+        winner: ranking[0],
+        median_grades,
+        ranking,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ballots() -> Vec<ScoreBallot> {
+        vec![
+            ScoreBallot::new(vec![5, 4, 0], 40),
+            ScoreBallot::new(vec![0, 4, 5], 35),
+            ScoreBallot::new(vec![0, 5, 2], 25),
+        ]
+    }
+
+    #[test]
+    fn test_score() {
+        let sum = compute_score(&ballots(), 3, ScoreAggregation::Sum).unwrap();
+        assert_eq!(sum.winner, 1);
+        assert_eq!(sum.scores, vec![200.0, 425.0, 225.0]);
+
+        let average = compute_score(&ballots(), 3, ScoreAggregation::Average).unwrap();
+        assert_eq!(average.winner, 1);
+        assert_eq!(average.scores, vec![2.0, 4.25, 2.25]);
+    }
+
+    #[test]
+    fn test_star_runoff_overturns_scores() {
+        let ballots = vec![
+            ScoreBallot::new(vec![5, 0, 0], 2),
+            ScoreBallot::new(vec![3, 4, 0], 3),
+        ];
+
+        let result = compute_star(&ballots, 3).unwrap();
+        assert_eq!(result.scores, vec![19.0, 12.0, 0.0]);
+        assert_eq!(result.finalists, [0, 1]);
+        assert_eq!(result.preferences, [2, 3]);
+        assert_eq!(result.no_preference, 0);
+        assert_eq!(result.winner, 1);
+
+        let result = compute_star(&self::ballots(), 3).unwrap();
+        assert_eq!(result.finalists, [1, 2]);
+        assert_eq!(result.preferences, [65, 35]);
+        assert_eq!(result.winner, 1);
+    }
+
+    #[test]
+    fn test_majority_judgment() {
+        let result = compute_majority_judgment(&ballots(), 3).unwrap();
+
+        assert_eq!(result.median_grades, vec![0, 4, 2]);
+        assert_eq!(result.ranking, vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn test_majority_judgment_tie_break() {
+        // Both medians are 2; removing it leaves 1 for candidate 0 and 2 for candidate 1.
+        let ballots = vec![
+            ScoreBallot::new(vec![1, 2], 1),
+            ScoreBallot::new(vec![2, 2], 1),
+            ScoreBallot::new(vec![3, 3], 1),
+        ];
+
+        let result = compute_majority_judgment(&ballots, 2).unwrap();
+        assert_eq!(result.median_grades, vec![2, 2]);
+        assert_eq!(result.winner, 1);
+    }
+
+    #[test]
+    fn test_errors() {
+        let ballots = vec![ScoreBallot::new(vec![1, 2], 1)];
+
+        assert_eq!(
+            compute_score(&ballots, 0, ScoreAggregation::Sum),
+            Err("EMPTY_RESULTS")
+        );
+        assert_eq!(compute_star(&ballots, 3), Err("INVALID_BALLOT"));
+        assert_eq!(
+            compute_star(&[ScoreBallot::new(vec![1], 1)], 1),
+            Err("NOT_ENOUGH_CANDIDATES")
+        );
+    }
+}
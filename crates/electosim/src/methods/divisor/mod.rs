@@ -6,7 +6,7 @@
 
 use crate::{
     interface::{WithSeats, WithVotes},
-    utils::clear_results,
+    utils::{clear_results, compute_total_votes},
 };
 
 /// A factory for divisor methods.
@@ -33,11 +33,11 @@ use crate::{
 ///
 /// compute_divisor_method(&mut candidacies, 13, |s| (s + 1) as f32).unwrap();
 /// ```
-pub fn compute_divisor_method<'a, T>(
-    results: &'a mut Vec<T>,
+pub fn compute_divisor_method<T>(
+    results: &mut [T],
     seats: u16,
     divisor: impl Fn(u16) -> f32,
-) -> Result<(), &'a str>
+) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -65,8 +65,74 @@ where
     Ok(())
 }
 
+/// A factory for divisor methods where only the candidates reaching a fraction of the quota take part.
+///
+/// The quota is computed over the votes of all the candidates. The rest of the candidates receive no seats.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidates.
+/// * `seats` - The number of seats available in the election.
+/// * `divisor` - A function that takes the number of seats won by the candidate and returns a float number.
+/// * `quota_fn` - A function that takes the total number of votes and the number of seats available and returns a float number.
+/// * `min_quotas` - The fraction of the quota a candidate needs to take part.
+///
+/// # Returns
+///
+/// `Err("EMPTY_RESULTS")` if there are no candidates, or `Err("NO_ELIGIBLE_CANDIDACIES")` if no candidate reaches the threshold.
+///
+/// # Example (Brazil: D'Hondt among the parties reaching the Hare quota)
+///
+/// ```rust
+/// use electosim::methods::divisor::compute_divisor_method_with_quota_threshold;
+/// use electosim::interface::WithSeats;
+/// use electosim::models::Candidacy;
+///
+/// let mut candidacies = vec![
+///    Candidacy::new(5000, 0),
+///    Candidacy::new(3000, 0),
+///    Candidacy::new(1100, 0),
+///    Candidacy::new(900, 0),
+/// ];
+///
+/// let hare = |total_votes, seats| total_votes as f32 / seats as f32;
+///
+/// compute_divisor_method_with_quota_threshold(&mut candidacies, 8, |s| (s + 1) as f32, hare, 1.0).unwrap();
+/// assert_eq!(candidacies.iter().map(|c| c.get_seats()).collect::<Vec<_>>(), vec![5, 3, 0, 0]);
+///
+/// let mut empty: Vec<Candidacy> = vec![];
+/// assert_eq!(compute_divisor_method_with_quota_threshold(&mut empty, 8, |s| (s + 1) as f32, hare, 1.0), Err("EMPTY_RESULTS"));
+/// ```
+pub fn compute_divisor_method_with_quota_threshold<T>(
+    results: &mut [T],
+    seats: u16,
+    divisor: impl Fn(u16) -> f32,
+    quota_fn: impl Fn(u32, u16) -> f32,
+    min_quotas: f32,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+    if results.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+    let quota = quota_fn(compute_total_votes(results), seats);
+
+    let mut eligible = results
+        .iter_mut()
+        .filter(|c| c.get_votes() as f32 >= min_quotas * quota)
+        .map(Box::new)
+        .collect::<Vec<_>>();
+
+    match compute_divisor_method(&mut eligible, seats, divisor) {
+        Err("EMPTY_RESULTS") => Err("NO_ELIGIBLE_CANDIDACIES"),
+        result => result,
+    }
+}
+
 #[allow(dead_code)]
-pub fn compute_dhondt<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_dhondt<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -74,7 +140,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_sainte_lague<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_sainte_lague<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -82,7 +148,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_adams<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_adams<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -90,7 +156,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_imperiali<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_imperiali<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -98,7 +164,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_huntington_hill<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_huntington_hill<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -106,7 +172,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_danish<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_danish<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -114,7 +180,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_wta<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_wta<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -1,9 +1,15 @@
+pub mod approval;
+pub mod cardinal;
 pub mod divisor;
+pub mod plurality;
+pub mod quota;
 pub mod remainder;
+pub mod two_round;
 
 use crate::interface::{WithSeats, WithVotes};
 
 use divisor::*;
+use quota::*;
 use remainder::*;
 
 #[allow(dead_code)]
@@ -20,10 +26,11 @@ pub enum Method {
     WINNERTAKESALL,
     HAGENBASCHBISCHOFF,
     IMPERIALIQUOTIENT,
+    BALINSKIYOUNG,
 }
 
 #[allow(unreachable_patterns)]
-pub fn get_method_function<T>(method: Method) -> fn(&mut Vec<T>, u16) -> Result<(), &str>
+pub fn get_method_function<T>(method: Method) -> fn(&mut [T], u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -39,6 +46,7 @@ where
         Method::HAGENBASCHBISCHOFF => compute_hagenbach_bischoff,
         Method::IMPERIALIQUOTIENT => compute_imperiali_quotient,
         Method::DROOP => compute_droop,
+        Method::BALINSKIYOUNG => compute_balinski_young,
     }
 }
 
@@ -48,7 +56,7 @@ mod tests {
     use super::*;
     use crate::models::Candidacy;
 
-    const METHODS: [Method; 11] = [
+    const METHODS: [Method; 12] = [
         Method::DHONDT,
         Method::HARE,
         Method::DROOP,
@@ -60,6 +68,7 @@ mod tests {
         Method::WINNERTAKESALL,
         Method::HAGENBASCHBISCHOFF,
         Method::IMPERIALIQUOTIENT,
+        Method::BALINSKIYOUNG,
     ];
 
     #[test]
@@ -0,0 +1,289 @@
+//! # Candidate-based plurality methods
+//! Multi-member districts where voters vote for individual candidates and the most voted candidates are elected.
+//! The systems differ in the number of votes each voter can cast:
+//! - Single Non-Transferable Vote (SNTV): one vote (ex: Japan until 1993).
+//! - Block vote: as many votes as seats (ex: many municipal councils).
+//! - Limited vote: fewer votes than seats (ex: 3 votes for 4 seats in the Spanish Senate).
+//!
+//! At candidate level, every candidate is a candidacy winning at most one seat (see [compute_most_voted]).
+//! At party level, the support of each party is spread across its nominated candidates (see [compute_party_plurality]).
+
+use crate::{
+    interface::{WithSeats, WithVotes},
+    methods::approval::ApprovalBallot,
+    utils::clear_results,
+};
+
+/// A candidate-based plurality system.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PluralitySystem {
+    /// Single Non-Transferable Vote: one vote per voter.
+    Sntv,
+    /// Block vote: as many votes per voter as seats.
+    Block,
+    /// Limited vote: a fixed number of votes per voter.
+    Limited(u16),
+}
+
+impl PluralitySystem {
+    /// Returns the number of votes each voter can cast in a district with `seats` seats.
+    pub fn votes_per_voter(&self, seats: u16) -> u16 {
+        match *self {
+            PluralitySystem::Sntv => 1,
+            PluralitySystem::Block => seats,
+            PluralitySystem::Limited(votes) => votes.min(seats),
+        }
+    }
+}
+
+/// Elects the `seats` most voted candidates, one seat each.
+///
+/// Ties are broken in favour of the candidate with the lowest index.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidates.
+/// * `seats` - The number of seats available in the district.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::methods::plurality::compute_most_voted;
+/// use electosim::interface::WithSeats;
+/// use electosim::models::Candidacy;
+///
+/// let mut candidates = vec![
+///     Candidacy::new(300, 0),
+///     Candidacy::new(500, 0),
+///     Candidacy::new(400, 0),
+/// ];
+///
+/// compute_most_voted(&mut candidates, 2).unwrap();
+/// assert_eq!(candidates.iter().map(|c| c.get_seats()).collect::<Vec<_>>(), vec![0, 1, 1]);
+/// ```
+pub fn compute_most_voted<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+
+    if results.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if seats as usize > results.len() {
+        return Err("NOT_ENOUGH_CANDIDATES");
+    }
+
+    let mut ranking: Vec<usize> = (0..results.len()).collect();
+    ranking.sort_by(|&a, &b| results[b].get_votes().cmp(&results[a].get_votes()));
+
+    ranking
+        .iter()
+        .take(seats as usize)
+        .for_each(|&idx| results[idx].set_seats(1));
+
+    Ok(())
+}
+
+/// Counts the votes of each candidate from the ballots.
+///
+/// # Arguments
+///
+/// * `ballots` - The ballots, each one marking the indexes of the chosen candidates.
+/// * `candidates` - The number of candidates.
+/// * `seats` - The number of seats available in the district.
+/// * `system` - The plurality system, which limits the number of marks per ballot.
+///
+/// # Returns
+///
+/// The votes of each candidate, or `Err("INVALID_BALLOT")` if a ballot marks an unknown candidate,
+/// marks a candidate twice or has more marks than allowed.
+pub fn tally_ballots(
+    ballots: &[ApprovalBallot],
+    candidates: usize,
+    seats: u16,
+    system: PluralitySystem,
+) -> Result<Vec<u32>, &'static str> {
+    let max_marks = system.votes_per_voter(seats) as usize;
+    let mut votes = vec![0u32; candidates];
+
+    for ballot in ballots {
+        let marks = &ballot.approvals;
+
+        if marks.len() > max_marks
+            || marks.iter().any(|&c| c >= candidates)
+            || marks
+                .iter()
+                .enumerate()
+                .any(|(i, c)| marks[..i].contains(c))
+        {
+            return Err("INVALID_BALLOT");
+        }
+
+        marks.iter().for_each(|&c| votes[c] += ballot.weight);
+    }
+
+    Ok(votes)
+}
+
+/// Simulates a candidate-based plurality election from party support.
+///
+/// Each party nominates a number of candidates and its voters spread their votes evenly across them:
+/// with `k` votes per voter and `m` nominees, every nominee receives `votes * min(k, m) / m` votes.
+/// The most voted candidates are elected and the seats of each party are set on `results`.
+///
+/// Ties are broken in favour of the party with the lowest index.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of parties.
+/// * `nominees` - The number of candidates nominated by each party.
+/// * `seats` - The number of seats available in the district.
+/// * `system` - The plurality system.
+///
+/// # Example (Spanish Senate)
+///
+/// ```rust
+/// use electosim::methods::plurality::{compute_party_plurality, PluralitySystem};
+/// use electosim::interface::WithSeats;
+/// use electosim::models::Candidacy;
+///
+/// let mut parties = vec![
+///     Candidacy::new(45000, 0),
+///     Candidacy::new(35000, 0),
+///     Candidacy::new(20000, 0),
+/// ];
+///
+/// compute_party_plurality(&mut parties, &[3, 3, 3], 4, PluralitySystem::Limited(3)).unwrap();
+/// assert_eq!(parties.iter().map(|c| c.get_seats()).collect::<Vec<_>>(), vec![3, 1, 0]);
+/// ```
+pub fn compute_party_plurality<T>(
+    results: &mut [T],
+    nominees: &[u16],
+    seats: u16,
+    system: PluralitySystem,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+
+    if results.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if nominees.len() != results.len() {
+        return Err("NOMINEES_MISMATCH");
+    }
+
+    if nominees.iter().map(|&m| m as u32).sum::<u32>() < seats as u32 {
+        return Err("NOT_ENOUGH_CANDIDATES");
+    }
+
+    let k = system.votes_per_voter(seats) as f64;
+    let candidate_votes = results
+        .iter()
+        .zip(nominees)
+        .map(|(p, &m)| match m {
+            0 => f64::NEG_INFINITY,
+            m => p.get_votes() as f64 * k.min(m as f64) / m as f64,
+        })
+        .collect::<Vec<_>>();
+
+    let mut ranking: Vec<usize> = (0..results.len()).collect();
+    ranking.sort_by(|&a, &b| candidate_votes[b].total_cmp(&candidate_votes[a]));
+
+    let mut seats_left = seats;
+    for idx in ranking {
+        let won = nominees[idx].min(seats_left);
+        results[idx].set_seats(won);
+        seats_left -= won;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::Candidacy, utils::compute_total_seats};
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_party_plurality_systems() {
+        let mut parties = vec![
+            Candidacy::new(4500, 0),
+            Candidacy::new(3500, 0),
+            Candidacy::new(2000, 0),
+        ];
+
+        compute_party_plurality(&mut parties, &[4, 4, 4], 4, PluralitySystem::Block).unwrap();
+        assert_eq!(seats(&parties), vec![4, 0, 0]);
+
+        compute_party_plurality(&mut parties, &[3, 3, 3], 4, PluralitySystem::Limited(3)).unwrap();
+        assert_eq!(seats(&parties), vec![3, 1, 0]);
+
+        // SNTV: over-nomination splits the vote of the largest party.
+        compute_party_plurality(&mut parties, &[4, 2, 1], 4, PluralitySystem::Sntv).unwrap();
+        assert_eq!(seats(&parties), vec![1, 2, 1]);
+
+        compute_party_plurality(&mut parties, &[2, 1, 1], 4, PluralitySystem::Sntv).unwrap();
+        assert_eq!(seats(&parties), vec![2, 1, 1]);
+        assert_eq!(compute_total_seats(&parties), 4);
+    }
+
+    #[test]
+    fn test_tally_ballots() {
+        let ballots = vec![
+            ApprovalBallot::new(vec![0, 1, 2], 10),
+            ApprovalBallot::new(vec![3, 4], 7),
+            ApprovalBallot::new(vec![2], 2),
+        ];
+
+        let votes = tally_ballots(&ballots, 5, 4, PluralitySystem::Limited(3)).unwrap();
+        assert_eq!(votes, vec![10, 10, 12, 7, 7]);
+
+        let mut candidates = votes
+            .iter()
+            .map(|&v| Candidacy::new(v, 0))
+            .collect::<Vec<_>>();
+        compute_most_voted(&mut candidates, 4).unwrap();
+        assert_eq!(seats(&candidates), vec![1, 1, 1, 1, 0]);
+
+        assert_eq!(
+            tally_ballots(&ballots, 5, 4, PluralitySystem::Sntv),
+            Err("INVALID_BALLOT")
+        );
+        assert_eq!(
+            tally_ballots(
+                &[ApprovalBallot::new(vec![1, 1], 1)],
+                5,
+                4,
+                PluralitySystem::Block
+            ),
+            Err("INVALID_BALLOT")
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut parties = vec![Candidacy::new(10, 0), Candidacy::new(5, 0)];
+
+        assert_eq!(
+            compute_most_voted(&mut parties, 3),
+            Err("NOT_ENOUGH_CANDIDATES")
+        );
+        assert_eq!(
+            compute_party_plurality(&mut parties, &[1], 1, PluralitySystem::Sntv),
+            Err("NOMINEES_MISMATCH")
+        );
+        assert_eq!(
+            compute_party_plurality(&mut parties, &[1, 1], 3, PluralitySystem::Sntv),
+            Err("NOT_ENOUGH_CANDIDATES")
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! # Quota methods
+//! Seats are assigned one by one as in the [divisor methods][crate::methods::divisor], but a candidate can only receive
+//! a new seat if it does not exceed its upper quota for the new house size.
+//! Quota methods are house-monotone (a larger house never takes seats away) and satisfy quota,
+//! avoiding both the Alabama paradox of the [remainder methods][crate::methods::remainder] and the quota violations of the divisor methods.
+//!
+//! All quota methods are implemented based on the [compute_quota_method] function.
+
+use crate::{
+    interface::{WithSeats, WithVotes},
+    utils::{clear_results, compute_total_votes},
+};
+
+/// A factory for quota methods.
+///
+/// For every house size `h`, a candidate with `s` seats is eligible for the next seat if `s < votes * (h + 1) / total_votes`.
+/// The seat goes to the eligible candidate with the highest result of the division of its votes by the divisor.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidates.
+/// * `seats` - The number of seats available in the election.
+/// * `divisor` - A function that takes the number of seats won by the candidate and returns a float number.
+///
+/// # Example (Balinski-Young method)
+///
+/// ```rust
+/// use electosim::methods::quota::compute_quota_method;
+/// use electosim::models::Candidacy;
+///
+/// let mut candidacies = vec![
+///    Candidacy::new(2010, 0),
+///    Candidacy::new(1018, 0),
+///    Candidacy::new(86, 0),
+///    Candidacy::new(77, 0),
+/// ];
+///
+/// compute_quota_method(&mut candidacies, 13, |s| (s + 1) as f32).unwrap();
+/// ```
+pub fn compute_quota_method<T>(
+    results: &mut [T],
+    seats: u16,
+    divisor: impl Fn(u16) -> f32,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+    let total_votes = compute_total_votes(results) as u64;
+
+    for house in 1..=seats as u64 {
+        let better_idx = results
+            .iter()
+            .map(|c| {
+                let votes = c.get_votes() as u64;
+                let seats = c.get_seats();
+
+                // seats < votes * house / total_votes
+                match total_votes == 0 || seats as u64 * total_votes < votes * house {
+                    true => c.get_votes() as f32 / divisor(seats),
+                    false => f32::NEG_INFINITY,
+                }
+            })
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        match better_idx {
+            Some((idx, _)) => results[idx].increase_seats(1),
+            None => return Err("EMPTY_RESULTS"),
+        }
+    }
+
+    Ok(())
+}
+
+/// The Balinski-Young quota method, based on the D'Hondt divisor.
+pub fn compute_balinski_young<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    compute_quota_method(results, seats, |s| (s + 1) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{methods::divisor::compute_dhondt, models::Candidacy};
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_upper_quota() {
+        let mut candidacies = vec![
+            Candidacy::new(900, 0),
+            Candidacy::new(60, 0),
+            Candidacy::new(40, 0),
+        ];
+
+        // D'Hondt gives 10 seats to a candidacy with a quota of 9.
+        compute_dhondt(&mut candidacies, 10).unwrap();
+        assert_eq!(seats(&candidacies), vec![10, 0, 0]);
+
+        compute_balinski_young(&mut candidacies, 10).unwrap();
+        assert_eq!(seats(&candidacies), vec![9, 1, 0]);
+    }
+
+    #[test]
+    fn test_house_monotone_and_quota() {
+        let mut candidacies = vec![
+            Candidacy::new(5117, 0),
+            Candidacy::new(4400, 0),
+            Candidacy::new(162, 0),
+            Candidacy::new(161, 0),
+            Candidacy::new(80, 0),
+        ];
+        let total_votes = compute_total_votes(&candidacies) as f32;
+        let mut previous = vec![0; candidacies.len()];
+
+        for house in 1..=60 {
+            compute_balinski_young(&mut candidacies, house).unwrap();
+            let current = seats(&candidacies);
+
+            assert!(current.iter().zip(&previous).all(|(c, p)| c >= p));
+            assert!(candidacies.iter().all(|c| {
+                let quota = c.get_votes() as f32 * house as f32 / total_votes;
+                c.get_seats() as f32 <= quota.ceil() && c.get_seats() as f32 >= quota.floor()
+            }));
+
+            previous = current;
+        }
+    }
+}
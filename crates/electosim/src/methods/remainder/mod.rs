@@ -1,6 +1,9 @@
 //! # Remainder methods
 //! In the remainder methods, the votes of each candidate are divided by the quota to obtain the number of seats each candidate will receive. The quota depends on the method used. The integer part of the division is the number of seats won by the candidate, and the remainder is used to assign the remaining seats.
 //!
+//! With quotas smaller than the Hare quota (ex: Hagenbach-Bischoff or Imperiali), the integer parts can add up to more seats than available.
+//! In that case the excess seats are removed, one by one, from the candidates with the smallest remainders.
+//!
 //! All remainder methods are implemented based on the [compute_remainder_method] function.
 
 use crate::{
@@ -8,14 +11,33 @@ use crate::{
     utils::{clear_results, compute_total_votes},
 };
 
-use std::cmp::min;
 struct RemainderResult {
     pub integer: u16,
     pub remainder: f32,
+    pub eligible: bool,
+}
+
+/// Restricts the candidates taking part in each stage of a remainder method to those reaching a fraction of the quota.
+///
+/// The quota is always computed over the votes of all the candidates.
+///
+/// # Example
+///
+/// * Brazil: only parties reaching the electoral quotient take part, `QuotaThreshold { full_seats: 1.0, remainders: 1.0 }`.
+/// * Netherlands: only lists reaching a full quota receive remainder seats, `QuotaThreshold { full_seats: 0.0, remainders: 1.0 }`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct QuotaThreshold {
+    /// Fraction of the quota a candidate needs to receive seats in the full-seat stage.
+    pub full_seats: f32,
+    /// Fraction of the quota a candidate needs to receive seats in the remainder stage.
+    pub remainders: f32,
 }
 
 /// A factory for remainder methods.
 ///
+/// If the integer parts exceed the available seats, the excess is removed from the candidates with the smallest remainders,
+/// so the total number of seats assigned is always `seats`.
+///
 /// # Arguments
 ///
 /// * `results` - A mutable reference to a vector of candidates.
@@ -39,11 +61,63 @@ struct RemainderResult {
 ///
 /// compute_remainder_method(&mut candidacies, 13, quota_fn).unwrap();
 /// ```
-pub fn compute_remainder_method<'a, T>(
-    results: &'a mut Vec<T>,
+pub fn compute_remainder_method<T>(
+    results: &mut [T],
     seats: u16,
     quota_fn: impl Fn(u32, u16) -> f32,
-) -> Result<(), &'a str>
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    compute_remainder_method_with_quota_threshold(
+        results,
+        seats,
+        quota_fn,
+        QuotaThreshold::default(),
+    )
+}
+
+/// A factory for remainder methods where the eligibility of each stage depends on the quota.
+///
+/// Works as [compute_remainder_method], but candidates below `threshold.full_seats` quotas receive no full seats
+/// and candidates below `threshold.remainders` quotas receive no remainder seats.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidates.
+/// * `seats` - The number of seats available in the election.
+/// * `quota_fn` - A function that takes the total number of votes and the number of seats available and returns a float number.
+/// * `threshold` - The fraction of the quota needed in each stage.
+///
+/// # Returns
+///
+/// `Err("NO_ELIGIBLE_CANDIDACIES")` if there are remainder seats left but no candidate is eligible for them.
+///
+/// # Example (Hare method, remainder seats only for lists with a full quota)
+///
+/// ```rust
+/// use electosim::methods::remainder::{compute_remainder_method_with_quota_threshold, QuotaThreshold};
+/// use electosim::interface::WithSeats;
+/// use electosim::models::Candidacy;
+///
+/// let mut candidacies = vec![
+///    Candidacy::new(5500, 0),
+///    Candidacy::new(3600, 0),
+///    Candidacy::new(900, 0),
+/// ];
+///
+/// let quota_fn = |total_votes, seats| total_votes as f32 / seats as f32;
+/// let threshold = QuotaThreshold { full_seats: 0.0, remainders: 1.0 };
+///
+/// compute_remainder_method_with_quota_threshold(&mut candidacies, 10, quota_fn, threshold).unwrap();
+/// assert_eq!(candidacies.iter().map(|c| c.get_seats()).collect::<Vec<_>>(), vec![6, 4, 0]);
+/// ```
+pub fn compute_remainder_method_with_quota_threshold<T>(
+    results: &mut [T],
+    seats: u16,
+    quota_fn: impl Fn(u32, u16) -> f32,
+    threshold: QuotaThreshold,
+) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -56,22 +130,47 @@ where
         .iter()
         .map(|r| {
             let votes = r.get_votes() as f32;
-            let integer = (votes / quota).floor() as u16;
+            let integer = match votes >= threshold.full_seats * quota {
+                true => (votes / quota).floor() as u16,
+                false => 0,
+            };
             let remainder = votes / quota - integer as f32;
+            let eligible = votes >= threshold.remainders * quota;
 
-            RemainderResult { integer, remainder }
+            RemainderResult {
+                integer,
+                remainder,
+                eligible,
+            }
         })
         .collect();
 
+    let mut assigned: u32 = remainders.iter().map(|r| r.integer as u32).sum();
+
+    while assigned > seats as u32 {
+        let worse_idx = remainders
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.integer > 0)
+            .min_by(|(_, a), (_, b)| a.remainder.total_cmp(&b.remainder));
+
+        if let Some((idx, _)) = worse_idx {
+            remainders[idx].integer -= 1;
+            remainders[idx].remainder += 1.0;
+            assigned -= 1;
+        }
+    }
+
     remainders.iter().enumerate().for_each(|(idx, r)| {
         results[idx].set_seats(r.integer);
-        seats_left -= min(r.integer, seats_left);
+        seats_left -= r.integer;
     });
 
     for _ in 0..seats_left {
         let better_idx = remainders
             .iter()
             .enumerate()
+            .filter(|(_, r)| r.eligible)
             .max_by(|(_, a), (_, b)| a.remainder.total_cmp(&b.remainder));
 
         match better_idx {
@@ -80,14 +179,15 @@ where
                 remainders[idx].integer += 1;
                 remainders[idx].remainder -= 1.0;
             }
-            None => return Err("EMPTY_RESULTS"),
+            None if remainders.is_empty() => return Err("EMPTY_RESULTS"),
+            None => return Err("NO_ELIGIBLE_CANDIDACIES"),
         }
     }
 
     Ok(())
 }
 
-pub fn compute_hare<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_hare<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -97,7 +197,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_droop<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_droop<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -107,7 +207,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_hagenbach_bischoff<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_hagenbach_bischoff<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -117,7 +217,7 @@ where
 }
 
 #[allow(dead_code)]
-pub fn compute_imperiali_quotient<T>(results: &mut Vec<T>, seats: u16) -> Result<(), &str>
+pub fn compute_imperiali_quotient<T>(results: &mut [T], seats: u16) -> Result<(), &'static str>
 where
     T: WithSeats + WithVotes,
 {
@@ -128,7 +228,15 @@ where
 
 #[cfg(test)]
 pub mod tests {
-    use crate::*;
+    use super::*;
+    use crate::{models::Candidacy, utils::compute_total_seats, *};
+
+    const REMAINDER_METHODS: [Method; 4] = [
+        Method::HARE,
+        Method::DROOP,
+        Method::HAGENBASCHBISCHOFF,
+        Method::IMPERIALIQUOTIENT,
+    ];
 
     #[test]
     fn test_bug_hagenbach_seats() {
@@ -145,6 +253,98 @@ pub mod tests {
         let mut ele = election![candidacies, seats, method, cutoff];
 
         ele.compute().expect("Can not compute method");
-        ele.results.iter().for_each(|c| println!("{:?}", c));
+        assert_eq!(compute_total_seats(&ele.results), seats);
+    }
+
+    #[test]
+    fn test_total_seats() {
+        let cases: Vec<(Vec<u32>, u16)> = vec![
+            (vec![2010, 1018, 86, 77], 13),
+            (vec![600, 31, 32], 1000),
+            (vec![90, 10], 1),
+            (vec![50, 50], 1),
+            (vec![34, 33, 33], 2),
+            (vec![1, 1, 1, 1, 1], 3),
+            (vec![4000, 3000, 2000, 1000], 7),
+        ];
+
+        for method in REMAINDER_METHODS.iter() {
+            let f = methods::get_method_function(*method);
+            for (votes, seats) in cases.iter() {
+                let mut candidacies = votes
+                    .iter()
+                    .map(|&v| Candidacy::new(v, 0))
+                    .collect::<Vec<_>>();
+
+                f(&mut candidacies, *seats).unwrap();
+                assert_eq!(compute_total_seats(&candidacies), *seats);
+            }
+        }
+    }
+
+    #[test]
+    fn test_quota_threshold() {
+        let hare = |total_votes, seats| total_votes as f32 / seats as f32;
+        let votes = [4600, 3100, 1300, 1000];
+        let mut candidacies = votes
+            .iter()
+            .map(|&v| Candidacy::new(v, 0))
+            .collect::<Vec<_>>();
+
+        compute_remainder_method(&mut candidacies, 8, hare).unwrap();
+        assert_eq!(
+            candidacies
+                .iter()
+                .map(|c| c.get_seats())
+                .collect::<Vec<_>>(),
+            vec![4, 2, 1, 1]
+        );
+
+        // Quota: 1250. The last list does not reach it and loses its remainder seat.
+        let threshold = QuotaThreshold {
+            full_seats: 0.0,
+            remainders: 1.0,
+        };
+        compute_remainder_method_with_quota_threshold(&mut candidacies, 8, hare, threshold)
+            .unwrap();
+        assert_eq!(
+            candidacies
+                .iter()
+                .map(|c| c.get_seats())
+                .collect::<Vec<_>>(),
+            vec![4, 3, 1, 0]
+        );
+
+        let threshold = QuotaThreshold {
+            full_seats: 5.0,
+            remainders: 5.0,
+        };
+        assert_eq!(
+            compute_remainder_method_with_quota_threshold(&mut candidacies, 8, hare, threshold),
+            Err("NO_ELIGIBLE_CANDIDACIES")
+        );
+    }
+
+    #[test]
+    fn test_excess_removed_from_smallest_remainder() {
+        // Imperiali quotient: floor(100 / 3) + 1 = 34, so the integer parts are 2 and 0 for 1 seat.
+        let mut candidacies = vec![Candidacy::new(90, 0), Candidacy::new(10, 0)];
+        compute_imperiali_quotient(&mut candidacies, 1).unwrap();
+        assert_eq!(candidacies[0].get_seats(), 1);
+
+        // Imperiali quotient: floor(1100 / 6) + 1 = 184, integer parts 3 (r=0.26), 1 (r=0.63) and 1 (r=0.09) for 4 seats.
+        let mut candidacies = vec![
+            Candidacy::new(600, 0),
+            Candidacy::new(300, 0),
+            Candidacy::new(200, 0),
+        ];
+        compute_imperiali_quotient(&mut candidacies, 4).unwrap();
+        assert_eq!(
+            candidacies
+                .iter()
+                .map(|c| c.get_seats())
+                .collect::<Vec<_>>(),
+            vec![3, 1, 0]
+        );
     }
 }
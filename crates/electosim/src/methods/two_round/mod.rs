@@ -0,0 +1,410 @@
+//! # Two-round system
+//! A single candidacy is elected. If no candidacy fulfils any of the first-round win rules,
+//! the qualified candidacies go to a second round, which is won by plurality.
+//!
+//! The second round is simulated from a vote transfer matrix between the first-round candidacies:
+//! `transfers[i][j]` is the fraction of the first-round voters of candidacy `i` that vote for candidacy `j` in the second round.
+//! The rest of the voters of `i` abstain. Columns of candidacies that did not qualify are ignored.
+//!
+//! # Example (French presidential election)
+//!
+//! ```rust
+//! use electosim::methods::two_round::{FirstRoundRule, Qualification, TwoRoundSystem};
+//! use electosim::*;
+//!
+//! let system = TwoRoundSystem::new(vec![FirstRoundRule::AbsoluteMajority], Qualification::TopTwo);
+//! let first_round = vec![candidacy!(400), candidacy!(350), candidacy!(250)];
+//! let transfers = vec![
+//!     vec![1.0, 0.0, 0.0],
+//!     vec![0.0, 1.0, 0.0],
+//!     vec![0.2, 0.6, 0.0],
+//! ];
+//!
+//! let result = system.compute(&first_round, &transfers).unwrap();
+//! assert_eq!(result.winner, 1);
+//! ```
+
+use crate::interface::WithVotes;
+
+/// A rule electing a candidacy in the first round.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FirstRoundRule {
+    /// More than half of the valid votes.
+    AbsoluteMajority,
+    /// More than half of the valid votes and at least a share of the registered voters
+    /// (ex: 25% in French legislative elections).
+    AbsoluteMajorityOfRegistered { share: f32, registered: u32 },
+    /// At least a share of the valid votes (ex: 45% in Argentina).
+    Share(f32),
+    /// At least a share of the valid votes and a lead over the runner-up, in share points
+    /// (ex: 40% and 10 points in Argentina).
+    ShareWithLead { share: f32, lead: f32 },
+}
+
+/// The rule deciding which candidacies go to the second round.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Qualification {
+    /// The two candidacies with the most votes.
+    TopTwo,
+    /// Every candidacy with at least a share of the registered voters (ex: 12.5% in French legislative elections).
+    /// If fewer than two candidacies reach it, the two with the most votes qualify.
+    RegisteredShare { share: f32, registered: u32 },
+}
+
+/// The outcome of the first round.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FirstRoundOutcome {
+    /// Index of the candidacy elected in the first round.
+    Elected(usize),
+    /// Indexes of the qualified candidacies, sorted by first-round votes.
+    Runoff(Vec<usize>),
+}
+
+/// The outcome of the second round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecondRound {
+    /// Indexes of the candidacies running in the second round.
+    pub qualifiers: Vec<usize>,
+    /// Votes of each qualifier, in the same order as `qualifiers`.
+    pub votes: Vec<u32>,
+    /// First-round voters that abstain in the second round.
+    pub abstention: u32,
+    /// Index of the winning candidacy.
+    pub winner: usize,
+}
+
+/// The outcome of a two-round election.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoRoundResult {
+    /// Index of the elected candidacy.
+    pub winner: usize,
+    /// The outcome of the first round.
+    pub first_round: FirstRoundOutcome,
+    /// The simulated second round, if any.
+    pub second_round: Option<SecondRound>,
+}
+
+/// A two-round system, defined by its first-round win rules and its qualification rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoRoundSystem {
+    /// A candidacy fulfilling any of these rules is elected in the first round.
+    pub first_round: Vec<FirstRoundRule>,
+    /// The rule deciding which candidacies go to the second round.
+    pub qualification: Qualification,
+}
+
+/// Returns the candidacy indexes sorted by votes, keeping the lowest index on ties.
+fn ranking<T: WithVotes>(results: &[T]) -> Vec<usize> {
+    let mut ranking: Vec<usize> = (0..results.len()).collect();
+    ranking.sort_by(|&a, &b| results[b].get_votes().cmp(&results[a].get_votes()));
+    ranking
+}
+
+impl FirstRoundRule {
+    fn is_fulfilled(&self, votes: u32, runner_up: u32, total_votes: u32) -> bool {
+        let vote_share = votes as f64 / total_votes as f64;
+        let lead = (votes as f64 - runner_up as f64) / total_votes as f64;
+
+        match *self {
+            FirstRoundRule::AbsoluteMajority => 2 * votes as u64 > total_votes as u64,
+            FirstRoundRule::AbsoluteMajorityOfRegistered { share, registered } => {
+                2 * votes as u64 > total_votes as u64
+                    && votes as f64 >= share as f64 * registered as f64
+            }
+            FirstRoundRule::Share(min) => vote_share >= min as f64,
+            FirstRoundRule::ShareWithLead {
+                share: min,
+                lead: min_lead,
+            } => vote_share >= min as f64 && lead >= min_lead as f64,
+        }
+    }
+}
+
+impl TwoRoundSystem {
+    /// Creates a new `TwoRoundSystem`.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_round` - A candidacy fulfilling any of these rules is elected in the first round.
+    /// * `qualification` - The rule deciding which candidacies go to the second round.
+    pub fn new(first_round: Vec<FirstRoundRule>, qualification: Qualification) -> Self {
+        TwoRoundSystem {
+            first_round,
+            qualification,
+        }
+    }
+
+    /// Decides whether a candidacy is elected in the first round or which candidacies qualify for the second one.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - The first-round results.
+    pub fn compute_first_round<T: WithVotes>(
+        &self,
+        results: &[T],
+    ) -> Result<FirstRoundOutcome, &'static str> {
+        let ranking = ranking(results);
+        let total_votes: u32 = results.iter().map(|c| c.get_votes()).sum();
+
+        let first = match ranking.first() {
+            Some(&idx) if total_votes > 0 => idx,
+            _ => return Err("EMPTY_RESULTS"),
+        };
+
+        let votes = results[first].get_votes();
+        let runner_up = ranking.get(1).map_or(0, |&idx| results[idx].get_votes());
+
+        if ranking.len() == 1
+            || self
+                .first_round
+                .iter()
+                .any(|r| r.is_fulfilled(votes, runner_up, total_votes))
+        {
+            return Ok(FirstRoundOutcome::Elected(first));
+        }
+
+        let qualifiers = match self.qualification {
+            Qualification::TopTwo => ranking[..2].to_vec(),
+            Qualification::RegisteredShare { share, registered } => {
+                let min_votes = share as f64 * registered as f64;
+                let qualified = ranking
+                    .iter()
+                    .take_while(|&&idx| results[idx].get_votes() as f64 >= min_votes)
+                    .count();
+
+                ranking[..qualified.max(2)].to_vec()
+            }
+        };
+
+        Ok(FirstRoundOutcome::Runoff(qualifiers))
+    }
+
+    /// Computes both rounds of the election.
+    ///
+    /// # Arguments
+    ///
+    /// * `results` - The first-round results.
+    /// * `transfers` - The vote transfer matrix between first-round candidacies (see the [module documentation](self)).
+    pub fn compute<T: WithVotes>(
+        &self,
+        results: &[T],
+        transfers: &[Vec<f32>],
+    ) -> Result<TwoRoundResult, &'static str> {
+        let first_round = self.compute_first_round(results)?;
+
+        match first_round {
+            FirstRoundOutcome::Elected(winner) => Ok(TwoRoundResult {
+                winner,
+                first_round,
+                second_round: None,
+            }),
+            FirstRoundOutcome::Runoff(ref qualifiers) => {
+                let second_round = compute_second_round(results, qualifiers, transfers)?;
+
+                Ok(TwoRoundResult {
+                    winner: second_round.winner,
+                    first_round,
+                    second_round: Some(second_round),
+                })
+            }
+        }
+    }
+}
+
+/// Simulates the second round from a vote transfer matrix.
+///
+/// The winner is the qualifier with the most votes. Ties are broken in favour of the qualifier listed first.
+///
+/// # Arguments
+///
+/// * `results` - The first-round results.
+/// * `qualifiers` - Indexes of the candidacies running in the second round.
+/// * `transfers` - The vote transfer matrix between first-round candidacies (see the [module documentation](self)).
+pub fn compute_second_round<T: WithVotes>(
+    results: &[T],
+    qualifiers: &[usize],
+    transfers: &[Vec<f32>],
+) -> Result<SecondRound, &'static str> {
+    let n = results.len();
+
+    if qualifiers.is_empty() {
+        return Err("EMPTY_RESULTS");
+    }
+
+    if qualifiers.iter().any(|&q| q >= n)
+        || transfers.len() != n
+        || transfers.iter().any(|row| {
+            row.len() != n
+                || row.iter().any(|&t| t < 0.0)
+                || qualifiers.iter().map(|&q| row[q]).sum::<f32>() > 1.0 + 1e-6
+        })
+    {
+        return Err("INVALID_TRANSFER_MATRIX");
+    }
+
+    let votes: Vec<u32> = qualifiers
+        .iter()
+        .map(|&q| {
+            results
+                .iter()
+                .zip(transfers)
+                .map(|(c, row)| c.get_votes() as f64 * row[q] as f64)
+                .sum::<f64>()
+                .round() as u32
+        })
+        .collect();
+
+    let total_votes: u32 = results.iter().map(|c| c.get_votes()).sum();
+    let abstention = total_votes.saturating_sub(votes.iter().sum());
+
+    let winner = votes
+        .iter()
+        .enumerate()
+        .fold(0, |best, (idx, &v)| match v > votes[best] {
+            true => idx,
+            false => best,
+        });
+
+    Ok(SecondRound {
+        winner: qualifiers[winner],
+        qualifiers: qualifiers.to_vec(),
+        votes,
+        abstention,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    fn argentina() -> TwoRoundSystem {
+        TwoRoundSystem::new(
+            vec![
+                FirstRoundRule::Share(0.45),
+                FirstRoundRule::ShareWithLead {
+                    share: 0.40,
+                    lead: 0.10,
+                },
+            ],
+            Qualification::TopTwo,
+        )
+    }
+
+    #[test]
+    fn test_first_round_rules() {
+        let system = argentina();
+
+        // 2019: 48.24% vs 40.28%
+        let results = vec![Candidacy::new(4824, 0), Candidacy::new(4028, 0)];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Elected(0))
+        );
+
+        // 41% with an 11 points lead.
+        let results = vec![
+            Candidacy::new(3000, 0),
+            Candidacy::new(4100, 0),
+            Candidacy::new(2900, 0),
+        ];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Elected(1))
+        );
+
+        // 2015: 37.08% vs 34.15%
+        let results = vec![
+            Candidacy::new(3708, 0),
+            Candidacy::new(3415, 0),
+            Candidacy::new(2877, 0),
+        ];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Runoff(vec![0, 1]))
+        );
+    }
+
+    #[test]
+    fn test_registered_share_qualification() {
+        let system = TwoRoundSystem::new(
+            vec![FirstRoundRule::AbsoluteMajorityOfRegistered {
+                share: 0.25,
+                registered: 1000,
+            }],
+            Qualification::RegisteredShare {
+                share: 0.125,
+                registered: 1000,
+            },
+        );
+
+        // Absolute majority, but below 25% of registered voters.
+        let results = vec![Candidacy::new(240, 0), Candidacy::new(200, 0)];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Runoff(vec![0, 1]))
+        );
+
+        // Three candidacies above 125 votes.
+        let results = vec![
+            Candidacy::new(130, 0),
+            Candidacy::new(200, 0),
+            Candidacy::new(126, 0),
+            Candidacy::new(124, 0),
+        ];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Runoff(vec![1, 0, 2]))
+        );
+
+        // Only one candidacy above 125 votes, the runner-up also qualifies.
+        let results = vec![
+            Candidacy::new(100, 0),
+            Candidacy::new(200, 0),
+            Candidacy::new(90, 0),
+        ];
+        assert_eq!(
+            system.compute_first_round(&results),
+            Ok(FirstRoundOutcome::Runoff(vec![1, 0]))
+        );
+    }
+
+    #[test]
+    fn test_second_round() {
+        let results = vec![
+            Candidacy::new(3708, 0),
+            Candidacy::new(3415, 0),
+            Candidacy::new(2877, 0),
+        ];
+        let transfers = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.3, 0.5, 0.0],
+        ];
+
+        let result = argentina().compute(&results, &transfers).unwrap();
+        let second_round = result.second_round.unwrap();
+
+        assert_eq!(result.winner, 1);
+        assert_eq!(second_round.votes, vec![4571, 4854]);
+        assert_eq!(second_round.abstention, 575);
+    }
+
+    #[test]
+    fn test_invalid_transfers() {
+        let results = vec![Candidacy::new(10, 0), Candidacy::new(10, 0)];
+
+        assert_eq!(
+            compute_second_round(&results, &[0, 1], &[vec![1.0, 0.0]]),
+            Err("INVALID_TRANSFER_MATRIX")
+        );
+        assert_eq!(
+            compute_second_round(&results, &[0, 1], &[vec![0.7, 0.7], vec![0.0, 1.0]]),
+            Err("INVALID_TRANSFER_MATRIX")
+        );
+        assert_eq!(
+            argentina().compute_first_round::<Candidacy>(&[]),
+            Err("EMPTY_RESULTS")
+        );
+    }
+}
@@ -4,6 +4,8 @@
 
 mod distorsion;
 mod enp;
+mod wasted;
 
 pub use distorsion::*;
 pub use enp::*;
+pub use wasted::*;
@@ -0,0 +1,210 @@
+use crate::{
+    interface::{WithSeats, WithVotes},
+    threshold::{Comparison, Threshold},
+};
+
+/// Calculate the votes of the candidacies not exceeding the cutoff, a fraction of the total votes.
+///
+/// The cutoff is compared as in [SimpleElection][crate::SimpleElection]: the votes must exceed it.
+///
+/// # Arguments
+///
+/// * `candidacies` - A slice of candidacies implementing the `WithVotes` trait.
+/// * `cutoff` - The electoral cutoff (ex: 0.03 for 3%).
+///
+/// # Returns
+///
+/// The votes of the candidacies below the cutoff.
+pub fn votes_below_cutoff<C: WithVotes>(candidacies: &[C], cutoff: f32) -> u32 {
+    let threshold = Threshold::percentage(cutoff).with_comparison(Comparison::Exclusive);
+    let total = candidacies.iter().map(|c| c.get_votes() as u64).sum();
+
+    candidacies
+        .iter()
+        .filter(|c| !threshold.passes(c.get_votes(), total, 1))
+        .map(|c| c.get_votes())
+        .sum()
+}
+
+/// Calculate the votes of the candidacies winning no seats.
+///
+/// # Arguments
+///
+/// * `candidacies` - A slice of candidacies implementing the `WithSeats` and `WithVotes` traits.
+pub fn votes_without_seats<C: WithSeats + WithVotes>(candidacies: &[C]) -> u32 {
+    candidacies
+        .iter()
+        .filter(|c| c.get_seats() == 0)
+        .map(|c| c.get_votes())
+        .sum()
+}
+
+/// Calculate the votes per seat of each candidacy, `None` for the candidacies winning no seats.
+///
+/// # Arguments
+///
+/// * `candidacies` - A slice of candidacies implementing the `WithSeats` and `WithVotes` traits.
+pub fn votes_per_seat<C: WithSeats + WithVotes>(candidacies: &[C]) -> Vec<Option<f32>> {
+    candidacies
+        .iter()
+        .map(|c| match c.get_seats() {
+            0 => None,
+            s => Some(c.get_votes() as f32 / s as f32),
+        })
+        .collect()
+}
+
+/// Calculate the surplus votes of each candidacy, the votes beyond those needed to win its seats.
+///
+/// A candidacy with `s` seats of `S` needs more than `s / (S + 1)` of the total votes (the Droop quota of its seats),
+/// which is more than half of the votes in a single-member district.
+///
+/// # Arguments
+///
+/// * `candidacies` - A slice of candidacies implementing the `WithSeats` and `WithVotes` traits.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::metrics::surplus_votes;
+/// use electosim::models::Candidacy;
+///
+/// // 1001 votes are needed to win a single seat out of 2000.
+/// let district = vec![Candidacy::new(1500, 1), Candidacy::new(500, 0)];
+/// assert_eq!(surplus_votes(&district), vec![499, 0]);
+/// ```
+pub fn surplus_votes<C: WithSeats + WithVotes>(candidacies: &[C]) -> Vec<u32> {
+    let total_votes: u64 = candidacies.iter().map(|c| c.get_votes() as u64).sum();
+    let total_seats: u64 = candidacies.iter().map(|c| c.get_seats() as u64).sum();
+
+    candidacies
+        .iter()
+        .map(|c| match c.get_seats() {
+            0 => 0,
+            s => {
+                let needed = s as u64 * total_votes / (total_seats + 1) + 1;
+                (c.get_votes() as u64).saturating_sub(needed) as u32
+            }
+        })
+        .collect()
+}
+
+/// Calculate the wasted votes of each candidacy: all its votes if it wins no seats, its surplus votes otherwise.
+///
+/// See [surplus_votes] for the votes needed to win the seats.
+///
+/// # Arguments
+///
+/// * `candidacies` - A slice of candidacies implementing the `WithSeats` and `WithVotes` traits.
+pub fn wasted_votes<C: WithSeats + WithVotes>(candidacies: &[C]) -> Vec<u32> {
+    candidacies
+        .iter()
+        .zip(surplus_votes(candidacies))
+        .map(|(c, surplus)| match c.get_seats() {
+            0 => c.get_votes(),
+            _ => surplus,
+        })
+        .collect()
+}
+
+/// Calculate the efficiency gap between two candidacies in each district.
+///
+/// See [efficiency_gap].
+pub fn efficiency_gaps<C: WithSeats + WithVotes>(
+    districts: &[Vec<C>],
+    a: usize,
+    b: usize,
+) -> Vec<f32> {
+    districts
+        .iter()
+        .map(|d| efficiency_gap(std::slice::from_ref(d), a, b))
+        .collect()
+}
+
+/// Calculate the efficiency gap between two candidacies across districts.
+///
+/// The efficiency gap is the difference between the [wasted votes][wasted_votes] of `a` and `b`, as a fraction of their
+/// votes. A positive gap means that `a` wastes more votes than `b`.
+///
+/// # Arguments
+///
+/// * `districts` - The candidacies of each district, the same index being the same party.
+/// * `a` - The index of the first candidacy.
+/// * `b` - The index of the second candidacy.
+///
+/// # Panics
+///
+/// If a district has no candidacy with index `a` or `b`.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::metrics::efficiency_gap;
+/// use electosim::models::Candidacy;
+///
+/// let districts = vec![
+///     vec![Candidacy::new(70, 1), Candidacy::new(30, 0)],
+///     vec![Candidacy::new(54, 1), Candidacy::new(46, 0)],
+///     vec![Candidacy::new(54, 1), Candidacy::new(46, 0)],
+/// ];
+///
+/// // Wasted votes: a 19 + 3 + 3 = 25, b 30 + 46 + 46 = 122.
+/// assert!((efficiency_gap(&districts, 0, 1) - (25.0 - 122.0) / 300.0).abs() < 1e-6);
+/// ```
+///
+/// See more at [Efficiency gap](https://en.wikipedia.org/wiki/Wasted_vote#Efficiency_gap)
+pub fn efficiency_gap<C: WithSeats + WithVotes>(districts: &[Vec<C>], a: usize, b: usize) -> f32 {
+    let (mut wasted_a, mut wasted_b, mut total) = (0i64, 0i64, 0i64);
+
+    for district in districts {
+        let wasted = wasted_votes(district);
+        wasted_a += wasted[a] as i64;
+        wasted_b += wasted[b] as i64;
+        total += district[a].get_votes() as i64 + district[b].get_votes() as i64;
+    }
+
+    match total {
+        0 => 0.0,
+        _ => (wasted_a - wasted_b) as f32 / total as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    #[test]
+    fn test_wasted_votes() {
+        // 10 seats, Droop quota of one seat: 1000 / 11.
+        let district = vec![
+            Candidacy::new(600, 6),
+            Candidacy::new(370, 4),
+            Candidacy::new(20, 0),
+            Candidacy::new(10, 0),
+        ];
+
+        assert_eq!(votes_below_cutoff(&district, 0.02), 30);
+        assert_eq!(votes_without_seats(&district), 30);
+        assert_eq!(
+            votes_per_seat(&district),
+            vec![Some(100.0), Some(92.5), None, None]
+        );
+        // 6 seats need 546 votes and 4 seats 364.
+        assert_eq!(surplus_votes(&district), vec![54, 6, 0, 0]);
+        assert_eq!(wasted_votes(&district), vec![54, 6, 20, 10]);
+    }
+
+    #[test]
+    fn test_efficiency_gaps() {
+        let districts = vec![
+            vec![Candidacy::new(60, 1), Candidacy::new(40, 0)],
+            vec![Candidacy::new(20, 0), Candidacy::new(80, 1)],
+        ];
+
+        let gaps = efficiency_gaps(&districts, 0, 1);
+        assert!((gaps[0] - (9.0 - 40.0) / 100.0).abs() < 1e-6);
+        assert!((gaps[1] - (20.0 - 29.0) / 100.0).abs() < 1e-6);
+        assert!((efficiency_gap(&districts, 0, 1) - (29.0 - 69.0) / 200.0).abs() < 1e-6);
+    }
+}
@@ -1,10 +1,17 @@
-use crate::interface::{WithSeats, WithVotes};
+use crate::{
+    interface::{WithCoalition, WithConstituencyWins, WithSeats, WithThresholdOverride, WithVotes},
+    threshold::ThresholdOverride,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// Represents a candidacy in an election.
 pub struct Candidacy {
+    id: Option<String>,
     votes: u32,
     seats: u16,
+    constituency_wins: u16,
+    coalition_parties: u16,
+    threshold_override: Option<ThresholdOverride>,
 }
 
 /// Represents a candidacy in an election.
@@ -26,7 +33,83 @@ pub struct Candidacy {
 /// ```
 impl Candidacy {
     pub fn new(votes: u32, seats: u16) -> Candidacy {
-        Candidacy { votes, seats }
+        Candidacy {
+            id: None,
+            votes,
+            seats,
+            constituency_wins: 0,
+            coalition_parties: 1,
+            threshold_override: None,
+        }
+    }
+
+    /// Sets the identifier of the party of the candidacy, used to combine results of several contests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use electosim::models::Candidacy;
+    ///
+    /// let candidacy = Candidacy::new(1000, 0).with_id("PSOE");
+    /// assert_eq!(candidacy.id(), Some("PSOE"));
+    /// ```
+    pub fn with_id(mut self, id: impl Into<String>) -> Candidacy {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the identifier of the party of the candidacy, if any.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Sets the number of constituencies won by the candidacy, used by the alternative clauses of thresholds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use electosim::interface::WithConstituencyWins;
+    /// use electosim::models::Candidacy;
+    ///
+    /// let candidacy = Candidacy::new(1000, 0).with_constituency_wins(3);
+    /// assert_eq!(candidacy.get_constituency_wins(), 3);
+    /// ```
+    pub fn with_constituency_wins(mut self, wins: u16) -> Candidacy {
+        self.constituency_wins = wins;
+        self
+    }
+
+    /// Sets the number of parties of a coalition candidacy, used by graded thresholds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use electosim::interface::WithCoalition;
+    /// use electosim::models::Candidacy;
+    ///
+    /// let candidacy = Candidacy::new(1000, 0).with_coalition_parties(2);
+    /// assert_eq!(candidacy.get_coalition_parties(), 2);
+    /// ```
+    pub fn with_coalition_parties(mut self, parties: u16) -> Candidacy {
+        self.coalition_parties = parties;
+        self
+    }
+
+    /// Sets a threshold rule for the candidacy, replacing the general one (ex: exempt national minorities).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use electosim::interface::WithThresholdOverride;
+    /// use electosim::models::Candidacy;
+    /// use electosim::threshold::ThresholdOverride;
+    ///
+    /// let candidacy = Candidacy::new(1000, 0).with_threshold_override(ThresholdOverride::Exempt);
+    /// assert_eq!(candidacy.get_threshold_override(), Some(ThresholdOverride::Exempt));
+    /// ```
+    pub fn with_threshold_override(mut self, rule: ThresholdOverride) -> Candidacy {
+        self.threshold_override = Some(rule);
+        self
     }
 }
 
@@ -50,6 +133,36 @@ impl WithSeats for Candidacy {
     }
 }
 
+impl WithConstituencyWins for Candidacy {
+    fn get_constituency_wins(&self) -> u16 {
+        self.constituency_wins
+    }
+
+    fn set_constituency_wins(&mut self, wins: u16) {
+        self.constituency_wins = wins;
+    }
+}
+
+impl WithCoalition for Candidacy {
+    fn get_coalition_parties(&self) -> u16 {
+        self.coalition_parties
+    }
+
+    fn set_coalition_parties(&mut self, parties: u16) {
+        self.coalition_parties = parties;
+    }
+}
+
+impl WithThresholdOverride for Candidacy {
+    fn get_threshold_override(&self) -> Option<ThresholdOverride> {
+        self.threshold_override
+    }
+
+    fn set_threshold_override(&mut self, rule: Option<ThresholdOverride>) {
+        self.threshold_override = rule;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,717 @@
+//! # Threshold
+//! Electoral thresholds exclude the candidacies with too few votes before the seats are distributed.
+//!
+//! A [Threshold] is defined by its value (a percentage or an absolute number of votes), the comparison used,
+//! the votes it is computed over and whether it applies to each district or to the national totals.
+//! It can be used with any [Method], either through [SimpleElection][crate::SimpleElection] or the `compute_` functions of this module.
+//!
+//! ```rust
+//! use electosim::threshold::{AlternativeClause, Threshold, ThresholdBase, ThresholdValue};
+//!
+//! // Spain: 3% of the valid votes of each province, blank ballots included.
+//! let spain = Threshold::percentage(0.03).with_base(ThresholdBase::ValidAndBlank { blank: 1200 });
+//!
+//! // Czech Republic: 5% for parties, 8% for two-party coalitions and 11% for larger ones.
+//! let czech = Threshold::percentage(0.05)
+//!     .national()
+//!     .with_coalition_value(2, ThresholdValue::Percentage(0.08))
+//!     .with_coalition_value(3, ThresholdValue::Percentage(0.11));
+//!
+//! // Germany: 5% of the national valid votes, or 3 constituencies won.
+//! let germany = Threshold::percentage(0.05)
+//!     .national()
+//!     .with_alternative(AlternativeClause::ConstituencyWins(3));
+//! ```
+
+use crate::{
+    interface::{WithCoalition, WithConstituencyWins, WithSeats, WithThresholdOverride, WithVotes},
+    methods::{get_method_function, Method},
+    models::Candidacy,
+    utils::clear_results,
+};
+
+/// The resolution of percentage thresholds, in parts of the base votes.
+const SHARE_RESOLUTION: u128 = 1_000_000;
+
+/// The value of a threshold.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThresholdValue {
+    /// A fraction of the base votes (ex: 0.05 for 5%).
+    Percentage(f32),
+    /// A number of votes.
+    Absolute(u32),
+}
+
+/// How the votes of a candidacy are compared with the threshold.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Comparison {
+    /// Reaching the threshold is enough.
+    Inclusive,
+    /// The threshold must be exceeded.
+    Exclusive,
+}
+
+/// The votes a percentage threshold is computed over.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThresholdBase {
+    /// The votes of the candidacies.
+    Valid,
+    /// The votes of the candidacies plus the blank ballots.
+    ValidAndBlank { blank: u32 },
+    /// The registered voters.
+    Registered { registered: u32 },
+}
+
+/// Where a threshold applies.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThresholdScope {
+    /// The votes of the candidacy in the district.
+    District,
+    /// The votes of the candidacy in all the districts.
+    National,
+}
+
+/// A condition qualifying a candidacy that does not pass the threshold.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AlternativeClause {
+    /// A minimum number of constituencies won (ex: 3 in Germany, 1 in New Zealand).
+    ConstituencyWins(u16),
+}
+
+/// A threshold rule of a single candidacy, replacing the general one (ex: national minorities).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThresholdOverride {
+    /// The candidacy takes part regardless of its votes.
+    Exempt,
+    /// The candidacy must reach this value instead of the threshold one.
+    Value(ThresholdValue),
+}
+
+/// Why a candidacy takes part, or not, in the distribution of seats.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThresholdQualification {
+    /// The candidacy passes the threshold.
+    Threshold,
+    /// The candidacy passes the value overriding the threshold for it.
+    Override,
+    /// The candidacy is exempt from the threshold.
+    Exempt,
+    /// The candidacy is qualified by an alternative clause.
+    Alternative(AlternativeClause),
+    /// The candidacy does not take part.
+    Excluded,
+}
+
+impl ThresholdQualification {
+    /// Checks whether the candidacy takes part in the distribution of seats.
+    pub fn is_qualified(&self) -> bool {
+        !matches!(self, ThresholdQualification::Excluded)
+    }
+}
+
+/// The data of a candidacy checked by a [Threshold].
+pub trait ThresholdCandidacy:
+    WithVotes + WithConstituencyWins + WithCoalition + WithThresholdOverride
+{
+}
+
+impl<T> ThresholdCandidacy for T where
+    T: WithVotes + WithConstituencyWins + WithCoalition + WithThresholdOverride
+{
+}
+
+/// An electoral threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Threshold {
+    /// The value a candidacy must reach.
+    pub value: ThresholdValue,
+    /// Whether reaching the value exactly passes the threshold.
+    pub comparison: Comparison,
+    /// The votes a percentage threshold is computed over.
+    pub base: ThresholdBase,
+    /// Whether the threshold is checked per district or with the national totals.
+    pub scope: ThresholdScope,
+    /// Conditions qualifying a candidacy below the threshold.
+    pub alternatives: Vec<AlternativeClause>,
+    /// The values applied to coalitions, with the minimum number of parties of the coalition.
+    pub coalitions: Vec<(u16, ThresholdValue)>,
+}
+
+impl Threshold {
+    /// Creates an inclusive district threshold over the valid votes.
+    pub fn new(value: ThresholdValue) -> Self {
+        Threshold {
+            value,
+            comparison: Comparison::Inclusive,
+            base: ThresholdBase::Valid,
+            scope: ThresholdScope::District,
+            alternatives: vec![],
+            coalitions: vec![],
+        }
+    }
+
+    /// Creates a threshold of a fraction of the votes (ex: 0.05 for 5%).
+    pub fn percentage(share: f32) -> Self {
+        Threshold::new(ThresholdValue::Percentage(share))
+    }
+
+    /// Creates a threshold of a number of votes.
+    pub fn absolute(votes: u32) -> Self {
+        Threshold::new(ThresholdValue::Absolute(votes))
+    }
+
+    /// Sets whether reaching the value exactly passes the threshold.
+    pub fn with_comparison(mut self, comparison: Comparison) -> Self {
+        self.comparison = comparison;
+        self
+    }
+
+    /// Sets the votes a percentage threshold is computed over.
+    pub fn with_base(mut self, base: ThresholdBase) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Sets whether the threshold is checked per district or with the national totals.
+    pub fn with_scope(mut self, scope: ThresholdScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Adds a condition qualifying a candidacy below the threshold.
+    pub fn with_alternative(mut self, clause: AlternativeClause) -> Self {
+        self.alternatives.push(clause);
+        self
+    }
+
+    /// Sets the value applied to coalitions of at least `parties` parties.
+    pub fn with_coalition_value(mut self, parties: u16, value: ThresholdValue) -> Self {
+        self.coalitions.push((parties, value));
+        self
+    }
+
+    /// Applies the threshold to the national totals.
+    pub fn national(self) -> Self {
+        self.with_scope(ThresholdScope::National)
+    }
+
+    /// Computes the votes a percentage threshold is computed over, given the valid votes.
+    pub fn base_votes(&self, valid: u32) -> u64 {
+        match self.base {
+            ThresholdBase::Valid => valid as u64,
+            ThresholdBase::ValidAndBlank { blank } => valid as u64 + blank as u64,
+            ThresholdBase::Registered { registered } => registered as u64,
+        }
+    }
+
+    /// Returns the value applied to a candidacy of `coalition_parties` parties.
+    ///
+    /// The coalition value with the most parties not exceeding `coalition_parties` is used, or the value of the threshold if there is none.
+    pub fn value_for(&self, coalition_parties: u16) -> ThresholdValue {
+        self.coalitions
+            .iter()
+            .filter(|(parties, _)| *parties <= coalition_parties)
+            .max_by_key(|(parties, _)| *parties)
+            .map(|(_, value)| *value)
+            .unwrap_or(self.value)
+    }
+
+    /// Checks whether `votes` of a candidacy of `coalition_parties` parties pass the threshold, given the base votes
+    /// computed with [Threshold::base_votes].
+    pub fn passes(&self, votes: u32, base_votes: u64, coalition_parties: u16) -> bool {
+        self.reaches(self.value_for(coalition_parties), votes, base_votes)
+    }
+
+    fn reaches(&self, value: ThresholdValue, votes: u32, base_votes: u64) -> bool {
+        // Shares are f32 (0.05 is 0.0500000007), so they are rounded to millionths and compared exactly.
+        let (votes, limit) = match value {
+            ThresholdValue::Percentage(share) => {
+                let share = (share as f64 * SHARE_RESOLUTION as f64).round() as u128;
+                (votes as u128 * SHARE_RESOLUTION, base_votes as u128 * share)
+            }
+            ThresholdValue::Absolute(limit) => (votes as u128, limit as u128),
+        };
+
+        match self.comparison {
+            Comparison::Inclusive => votes >= limit,
+            Comparison::Exclusive => votes > limit,
+        }
+    }
+
+    /// Returns the first alternative clause qualifying a candidacy, if any.
+    pub fn alternative_clause(&self, constituency_wins: u16) -> Option<AlternativeClause> {
+        self.alternatives
+            .iter()
+            .find(|clause| match **clause {
+                AlternativeClause::ConstituencyWins(wins) => constituency_wins >= wins,
+            })
+            .copied()
+    }
+
+    /// Checks whether any alternative clause qualifies a candidacy.
+    pub fn alternative_applies(&self, constituency_wins: u16) -> bool {
+        self.alternative_clause(constituency_wins).is_some()
+    }
+
+    /// Computes the qualification of a candidacy.
+    ///
+    /// Exemptions are checked first, then the override or the threshold value, and finally the alternative clauses.
+    pub fn qualification<T: ThresholdCandidacy>(
+        &self,
+        candidacy: &T,
+        base_votes: u64,
+    ) -> ThresholdQualification {
+        let votes = candidacy.get_votes();
+
+        let passes = match candidacy.get_threshold_override() {
+            Some(ThresholdOverride::Exempt) => return ThresholdQualification::Exempt,
+            Some(ThresholdOverride::Value(value)) => match self.reaches(value, votes, base_votes) {
+                true => Some(ThresholdQualification::Override),
+                false => None,
+            },
+            None => match self.passes(votes, base_votes, candidacy.get_coalition_parties()) {
+                true => Some(ThresholdQualification::Threshold),
+                false => None,
+            },
+        };
+
+        passes
+            .or_else(|| {
+                self.alternative_clause(candidacy.get_constituency_wins())
+                    .map(ThresholdQualification::Alternative)
+            })
+            .unwrap_or(ThresholdQualification::Excluded)
+    }
+
+    /// Computes the qualification of every candidacy in a single district.
+    ///
+    /// # Example (SSW, exempt in Schleswig-Holstein)
+    ///
+    /// ```rust
+    /// use electosim::threshold::{Threshold, ThresholdOverride, ThresholdQualification};
+    /// use electosim::*;
+    ///
+    /// let results = vec![
+    ///     candidacy!(960),
+    ///     candidacy!(30).with_threshold_override(ThresholdOverride::Exempt),
+    ///     candidacy!(10),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Threshold::percentage(0.05).qualifications(&results),
+    ///     vec![ThresholdQualification::Threshold, ThresholdQualification::Exempt, ThresholdQualification::Excluded]
+    /// );
+    /// ```
+    pub fn qualifications<T: ThresholdCandidacy>(
+        &self,
+        results: &[T],
+    ) -> Vec<ThresholdQualification> {
+        let valid = results.iter().map(|c| c.get_votes()).sum();
+        let base_votes = self.base_votes(valid);
+
+        results
+            .iter()
+            .map(|c| self.qualification(c, base_votes))
+            .collect()
+    }
+
+    /// Computes which candidacies take part in the distribution of seats in a single district.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use electosim::threshold::{Comparison, Threshold};
+    /// use electosim::*;
+    ///
+    /// let results = vec![candidacy!(95), candidacy!(5)];
+    ///
+    /// assert_eq!(Threshold::percentage(0.05).eligible(&results), vec![true, true]);
+    /// assert_eq!(
+    ///     Threshold::percentage(0.05).with_comparison(Comparison::Exclusive).eligible(&results),
+    ///     vec![true, false]
+    /// );
+    /// ```
+    pub fn eligible<T: ThresholdCandidacy>(&self, results: &[T]) -> Vec<bool> {
+        self.qualifications(results)
+            .iter()
+            .map(|q| q.is_qualified())
+            .collect()
+    }
+}
+
+/// Computes the seats with `method` among the eligible candidacies.
+///
+/// # Arguments
+///
+/// * `results` - A mutable reference to a vector of candidacies.
+/// * `seats` - The number of seats available.
+/// * `method` - The method used to distribute the seats.
+/// * `eligible` - Whether each candidacy takes part, in the same order as `results`.
+///
+/// # Returns
+///
+/// `Err("NO_ELIGIBLE_CANDIDACIES")` if no candidacy is eligible.
+pub fn compute_with_eligibility<T>(
+    results: &mut [T],
+    seats: u16,
+    method: Method,
+    eligible: &[bool],
+) -> Result<(), &'static str>
+where
+    T: WithSeats + WithVotes,
+{
+    clear_results(results);
+
+    let mut filtered = results
+        .iter_mut()
+        .zip(eligible)
+        .filter(|(_, &e)| e)
+        .map(|(c, _)| Box::new(c))
+        .collect::<Vec<_>>();
+
+    if filtered.is_empty() {
+        return Err("NO_ELIGIBLE_CANDIDACIES");
+    }
+
+    get_method_function(method)(&mut filtered, seats)
+}
+
+/// Computes the seats of a single district with `method` among the candidacies passing the threshold.
+///
+/// The scope of the threshold is irrelevant here, since the district holds all the votes.
+///
+/// # Example (Turkey: 7%)
+///
+/// ```rust
+/// use electosim::threshold::{compute_with_threshold, Threshold};
+/// use electosim::interface::WithSeats;
+/// use electosim::*;
+///
+/// let mut results = vec![candidacy!(6000), candidacy!(3400), candidacy!(600)];
+///
+/// compute_with_threshold(&mut results, 10, Method::DHONDT, &Threshold::percentage(0.07)).unwrap();
+/// assert_eq!(results.iter().map(|c| c.get_seats()).collect::<Vec<_>>(), vec![7, 3, 0]);
+/// ```
+pub fn compute_with_threshold<T>(
+    results: &mut [T],
+    seats: u16,
+    method: Method,
+    threshold: &Threshold,
+) -> Result<(), &'static str>
+where
+    T: WithSeats + ThresholdCandidacy,
+{
+    let eligible = threshold.eligible(results);
+    compute_with_eligibility(results, seats, method, &eligible)
+}
+
+/// Adds the candidacy of a party in one district to its national candidacy, as checked by national thresholds.
+///
+/// Votes and constituency wins are added. The party is a coalition if it is in any district, and its override is the
+/// first one found in the districts. Returns `Err("OUT_OF_RANGE")` if the national votes do not fit in a `u32`.
+pub(crate) fn add_to_national<T: ThresholdCandidacy>(
+    national: &mut Candidacy,
+    candidacy: &T,
+) -> Result<(), &'static str> {
+    let votes = national
+        .get_votes()
+        .checked_add(candidacy.get_votes())
+        .ok_or("OUT_OF_RANGE")?;
+    national.set_votes(votes);
+    national.set_constituency_wins(
+        national.get_constituency_wins() + candidacy.get_constituency_wins(),
+    );
+    national.set_coalition_parties(
+        national
+            .get_coalition_parties()
+            .max(candidacy.get_coalition_parties()),
+    );
+    if national.get_threshold_override().is_none() {
+        national.set_threshold_override(candidacy.get_threshold_override());
+    }
+
+    Ok(())
+}
+
+/// Computes the seats of several districts, each with its own threshold.
+///
+/// The candidacies of every district must be in the same order, the same index being the same party.
+/// District thresholds are checked with the votes of the district. National thresholds are checked with the votes of
+/// the party in all the districts, over the sum of the base votes of every district, and their alternative clauses with
+/// the constituencies won by the party in all the districts. The party is a coalition if it is in any district, and its
+/// override is the first one found in the districts.
+///
+/// # Arguments
+///
+/// * `districts` - The candidacies of each district.
+/// * `seats` - The seats of each district.
+/// * `method` - The method used in every district.
+/// * `thresholds` - The threshold of each district.
+///
+/// # Returns
+///
+/// `Err("DISTRICTS_MISMATCH")` if the districts do not have the same candidacies, seats and thresholds, and
+/// `Err("OUT_OF_RANGE")` if the national votes of a party do not fit in a `u32`.
+///
+/// # Example (national threshold)
+///
+/// ```rust
+/// use electosim::threshold::{compute_districts_with_threshold, Threshold};
+/// use electosim::interface::WithSeats;
+/// use electosim::*;
+///
+/// let mut districts = vec![
+///     vec![candidacy!(500), candidacy!(400), candidacy!(100)],
+///     vec![candidacy!(600), candidacy!(390), candidacy!(10)],
+/// ];
+/// let thresholds = vec![Threshold::percentage(0.06).national(); 2];
+///
+/// // The third party has 10% in the first district but 5.5% nationally.
+/// compute_districts_with_threshold(&mut districts, &[10, 10], Method::DHONDT, &thresholds).unwrap();
+/// assert_eq!(districts[0][2].get_seats(), 0);
+/// ```
+pub fn compute_districts_with_threshold<T>(
+    districts: &mut [Vec<T>],
+    seats: &[u16],
+    method: Method,
+    thresholds: &[Threshold],
+) -> Result<(), &'static str>
+where
+    T: WithSeats + ThresholdCandidacy,
+{
+    let parties = districts.first().map(|d| d.len()).unwrap_or(0);
+    if seats.len() != districts.len()
+        || thresholds.len() != districts.len()
+        || districts.iter().any(|d| d.len() != parties)
+    {
+        return Err("DISTRICTS_MISMATCH");
+    }
+
+    let mut national = (0..parties)
+        .map(|_| Candidacy::new(0, 0))
+        .collect::<Vec<_>>();
+    let mut national_base = 0u64;
+    for (district, threshold) in districts.iter().zip(thresholds) {
+        for (n, c) in national.iter_mut().zip(district.iter()) {
+            add_to_national(n, c)?;
+        }
+        national_base += threshold.base_votes(district.iter().map(|c| c.get_votes()).sum());
+    }
+
+    for ((district, &seats), threshold) in districts.iter_mut().zip(seats).zip(thresholds) {
+        let eligible = match threshold.scope {
+            ThresholdScope::District => threshold.eligible(district),
+            ThresholdScope::National => national
+                .iter()
+                .map(|n| threshold.qualification(n, national_base).is_qualified())
+                .collect(),
+        };
+
+        compute_with_eligibility(district, seats, method, &eligible)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Candidacy;
+
+    fn seats(results: &[Candidacy]) -> Vec<u16> {
+        results.iter().map(|c| c.get_seats()).collect()
+    }
+
+    #[test]
+    fn test_comparison_and_value() {
+        let results = vec![
+            Candidacy::new(900, 0),
+            Candidacy::new(50, 0),
+            Candidacy::new(50, 0),
+        ];
+
+        let inclusive = Threshold::percentage(0.05);
+        assert_eq!(inclusive.eligible(&results), vec![true, true, true]);
+
+        let exclusive = inclusive.clone().with_comparison(Comparison::Exclusive);
+        assert_eq!(exclusive.eligible(&results), vec![true, false, false]);
+
+        assert_eq!(
+            Threshold::absolute(51).eligible(&results),
+            vec![true, false, false]
+        );
+
+        // Shares are compared exactly, whatever the size of the electorate.
+        assert!(inclusive.passes(50_000_000, 1_000_000_000, 1));
+        assert!(!inclusive.passes(49_999_999, 1_000_000_000, 1));
+        assert!(!exclusive.passes(50_000_000, 1_000_000_000, 1));
+        assert!(exclusive.passes(50_000_001, 1_000_000_000, 1));
+    }
+
+    #[test]
+    fn test_base() {
+        let results = vec![Candidacy::new(970, 0), Candidacy::new(30, 0)];
+
+        // 3% of 1000 valid votes.
+        assert!(Threshold::percentage(0.03).eligible(&results)[1]);
+
+        // 3% of 1000 valid votes and 100 blank ballots.
+        let blank =
+            Threshold::percentage(0.03).with_base(ThresholdBase::ValidAndBlank { blank: 100 });
+        assert!(!blank.eligible(&results)[1]);
+
+        // 3% of 900 registered voters.
+        let registered =
+            Threshold::percentage(0.03).with_base(ThresholdBase::Registered { registered: 900 });
+        assert!(registered.eligible(&results)[1]);
+    }
+
+    #[test]
+    fn test_compute_with_threshold() {
+        let mut results = vec![
+            Candidacy::new(4000, 0),
+            Candidacy::new(3000, 0),
+            Candidacy::new(400, 0),
+        ];
+
+        compute_with_threshold(
+            &mut results,
+            7,
+            Method::SAINTELAGUE,
+            &Threshold::percentage(0.05),
+        )
+        .unwrap();
+        assert_eq!(seats(&results), vec![4, 3, 0]);
+
+        assert_eq!(
+            compute_with_threshold(&mut results, 7, Method::DHONDT, &Threshold::absolute(5000)),
+            Err("NO_ELIGIBLE_CANDIDACIES")
+        );
+    }
+
+    #[test]
+    fn test_districts_scope() {
+        let district = || {
+            vec![
+                vec![
+                    Candidacy::new(500, 0),
+                    Candidacy::new(400, 0),
+                    Candidacy::new(100, 0),
+                ],
+                vec![
+                    Candidacy::new(600, 0),
+                    Candidacy::new(390, 0),
+                    Candidacy::new(10, 0),
+                ],
+            ]
+        };
+
+        // 6% in each district: the third party passes in the first one.
+        let mut districts = district();
+        let thresholds = vec![Threshold::percentage(0.06); 2];
+        compute_districts_with_threshold(&mut districts, &[10, 10], Method::DHONDT, &thresholds)
+            .unwrap();
+        assert_eq!(seats(&districts[0]), vec![5, 4, 1]);
+        assert_eq!(seats(&districts[1]), vec![6, 4, 0]);
+
+        // 6% nationally: the third party has 110 of 2000 votes.
+        let mut districts = district();
+        let thresholds = vec![Threshold::percentage(0.06).national(); 2];
+        compute_districts_with_threshold(&mut districts, &[10, 10], Method::DHONDT, &thresholds)
+            .unwrap();
+        assert_eq!(seats(&districts[0]), vec![6, 4, 0]);
+
+        assert_eq!(
+            compute_districts_with_threshold(&mut districts, &[10], Method::DHONDT, &thresholds),
+            Err("DISTRICTS_MISMATCH")
+        );
+    }
+
+    #[test]
+    fn test_constituency_wins() {
+        let threshold =
+            Threshold::percentage(0.05).with_alternative(AlternativeClause::ConstituencyWins(3));
+
+        let mut results = vec![
+            Candidacy::new(600, 0),
+            Candidacy::new(360, 0),
+            Candidacy::new(40, 0).with_constituency_wins(3),
+        ];
+        compute_with_threshold(&mut results, 25, Method::SAINTELAGUE, &threshold).unwrap();
+        assert_eq!(seats(&results), vec![15, 9, 1]);
+
+        // Two wins are not enough.
+        results[2].set_constituency_wins(2);
+        assert_eq!(threshold.eligible(&results), vec![true, true, false]);
+
+        // National scope adds the wins of every district.
+        let mut districts = vec![
+            vec![
+                Candidacy::new(600, 0),
+                Candidacy::new(40, 0).with_constituency_wins(2),
+            ],
+            vec![
+                Candidacy::new(600, 0),
+                Candidacy::new(40, 0).with_constituency_wins(1),
+            ],
+        ];
+        let thresholds = vec![threshold.national(); 2];
+        compute_districts_with_threshold(&mut districts, &[16, 16], Method::DHONDT, &thresholds)
+            .unwrap();
+        assert_eq!(seats(&districts[1]), vec![15, 1]);
+    }
+
+    #[test]
+    fn test_coalitions() {
+        // Czech Republic: 5%, 8% for two parties and 11% for three or more.
+        let threshold = Threshold::percentage(0.05)
+            .with_coalition_value(3, ThresholdValue::Percentage(0.11))
+            .with_coalition_value(2, ThresholdValue::Percentage(0.08));
+
+        assert_eq!(threshold.value_for(1), ThresholdValue::Percentage(0.05));
+        assert_eq!(threshold.value_for(2), ThresholdValue::Percentage(0.08));
+        assert_eq!(threshold.value_for(4), ThresholdValue::Percentage(0.11));
+
+        let results = vec![
+            Candidacy::new(700, 0),
+            Candidacy::new(60, 0),
+            Candidacy::new(70, 0).with_coalition_parties(2),
+            Candidacy::new(80, 0).with_coalition_parties(2),
+            Candidacy::new(90, 0).with_coalition_parties(3),
+        ];
+        assert_eq!(
+            threshold.eligible(&results),
+            vec![true, true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_overrides() {
+        let threshold =
+            Threshold::percentage(0.05).with_alternative(AlternativeClause::ConstituencyWins(1));
+
+        let mut results = vec![
+            Candidacy::new(900, 0),
+            Candidacy::new(30, 0).with_threshold_override(ThresholdOverride::Exempt),
+            Candidacy::new(30, 0).with_threshold_override(ThresholdOverride::Value(
+                ThresholdValue::Percentage(0.03),
+            )),
+            Candidacy::new(20, 0).with_constituency_wins(1),
+            Candidacy::new(20, 0),
+        ];
+
+        assert_eq!(
+            threshold.qualifications(&results),
+            vec![
+                ThresholdQualification::Threshold,
+                ThresholdQualification::Exempt,
+                ThresholdQualification::Override,
+                ThresholdQualification::Alternative(AlternativeClause::ConstituencyWins(1)),
+                ThresholdQualification::Excluded,
+            ]
+        );
+
+        compute_with_threshold(&mut results, 50, Method::SAINTELAGUE, &threshold).unwrap();
+        assert_eq!(seats(&results), vec![45, 2, 2, 1, 0]);
+    }
+}
@@ -31,7 +31,7 @@ use crate::interface::{WithSeats, WithVotes};
 ///   assert_eq!(candidacies[3].get_seats(), 0);
 /// }
 /// ```
-pub fn clear_results<T>(results: &mut Vec<T>)
+pub fn clear_results<T>(results: &mut [T])
 where
     T: WithSeats,
 {
@@ -56,7 +56,7 @@ where
 ///     println!("Total votes: {}", total_votes);
 /// }
 /// ```
-pub fn compute_total_votes<T>(results: &Vec<T>) -> u32
+pub fn compute_total_votes<T>(results: &[T]) -> u32
 where
     T: WithVotes,
 {
@@ -82,13 +82,56 @@ where
 /// }
 /// ```
 #[allow(dead_code)]
-pub fn compute_total_seats<T>(results: &Vec<T>) -> u16
+pub fn compute_total_seats<T>(results: &[T]) -> u16
 where
     T: WithSeats,
 {
     results.iter().map(|x| x.get_seats()).sum()
 }
 
+/// Distributes a number of votes in proportion to a list of weights, keeping the total exact.
+///
+/// Every weight receives the integer part of its share, and the votes left go to the largest remainders. Negative
+/// weights count as zero, and every weight receives no votes if they add up to zero.
+///
+/// # Arguments
+///
+/// * `weights` - The weight of each candidate (ex: a projected share).
+/// * `total` - The number of votes to distribute.
+///
+/// # Example
+///
+/// ```rust
+/// use electosim::utils::distribute_votes;
+///
+/// fn main() {
+///     // 333.3 votes each, the vote left goes to the first candidate.
+///     assert_eq!(distribute_votes(&[1.0, 1.0, 1.0], 1000), vec![334, 333, 333]);
+/// }
+/// ```
+pub fn distribute_votes(weights: &[f64], total: u32) -> Vec<u32> {
+    let sum: f64 = weights.iter().map(|w| w.max(0.0)).sum();
+    if sum <= 0.0 {
+        return vec![0; weights.len()];
+    }
+
+    let exact = weights
+        .iter()
+        .map(|w| w.max(0.0) / sum * total as f64)
+        .collect::<Vec<_>>();
+    let mut votes = exact.iter().map(|e| e.floor() as u32).collect::<Vec<_>>();
+    let left = total.saturating_sub(votes.iter().sum());
+
+    let mut order = (0..exact.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor())));
+    order
+        .iter()
+        .take(left as usize)
+        .for_each(|&i| votes[i] += 1);
+
+    votes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,4 +164,13 @@ mod tests {
         assert_eq!(candidacies[2].get_seats(), 0);
         assert_eq!(candidacies[3].get_seats(), 0);
     }
+
+    #[test]
+    fn distributing_votes() {
+        let votes = distribute_votes(&[0.513, 0.462, 0.026], 1000);
+        assert_eq!(votes.iter().sum::<u32>(), 1000);
+
+        assert_eq!(distribute_votes(&[0.7, -0.1, 0.3], 10), vec![7, 0, 3]);
+        assert_eq!(distribute_votes(&[0.0, 0.0], 10), vec![0, 0]);
+    }
 }